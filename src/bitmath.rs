@@ -5,10 +5,15 @@
 
 /// Fetch n bits from x, started at bit s
 pub fn gb(x: u32, s: u8, n: u8) -> u32 {
-	  return (x >> s) & ((1 << n) - 1);
+	  (x >> s) & ((1 << n) - 1)
 }
 
 /// Checks if a bit in a value is set
 pub fn has_bit(x: u32, b: u8) -> bool {
-	  return (x & (1 << b)) != 0;
+	  (x & (1 << b)) != 0
+}
+
+/// Converts a 16 bits fraction to percent, like the game shows it
+pub fn to_percent16(x: u16) -> u8 {
+	  ((x as u32 * 101) >> 16) as u8
 }
@@ -0,0 +1,187 @@
+//! Cargo packet related types
+//!
+//! The cargo waiting on the stations, or loaded on the vehicles,
+//! is a list of references to the packets of this pool.
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/saveload/cargopacket_sl.cpp
+
+use crate::chunk_reader::ChunkReader;
+use crate::error::Error;
+use crate::money::{Money, currencies};
+
+/// No station, like the packets created before the station index was saved
+const INVALID_STATION: u16 = 0xFFFF;
+
+/// Kind of the source of a cargo packet, used by the subsidies
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SourceType {
+    Industry,
+    Town,
+    Headquarters,
+}
+
+impl TryFrom<u8> for SourceType {
+    type Error = Error;
+
+    fn try_from(source_type: u8) -> Result<Self, Self::Error> {
+        match source_type {
+            0 => Ok(SourceType::Industry),
+            1 => Ok(SourceType::Town),
+            2 => Ok(SourceType::Headquarters),
+            t => Err(Error::DataCorruption(format!("Unknown cargo source type: {}", t)))
+        }
+    }
+}
+
+/// An amount of cargo with the same origin
+#[derive(Debug, PartialEq)]
+pub struct CargoPacket {
+    /// Index of the packet on the pool
+    pub index: usize,
+    /// Station where the cargo was first loaded
+    pub source_station: Option<usize>,
+    /// Tile of the source station
+    pub source_xy: u32,
+    /// Tile where the cargo was loaded on the current vehicle
+    pub loaded_at_xy: u32,
+    /// Amount of cargo
+    pub count: u16,
+    /// Days in the vehicles, the longer the lower is the income
+    pub days_in_transit: u8,
+    /// Income already paid to the feeder vehicles, on transfers
+    pub feeder_share: Money,
+    /// Kind of the cargo source
+    pub source_type: SourceType,
+    /// Index of the industry, town or company of the source
+    pub source_id: u16,
+}
+
+impl CargoPacket {
+
+    /// Parse all the cargo packets
+    pub fn parse_list(buffer: &[u8]) -> Result<Vec<CargoPacket>, Error> {
+
+        let mut chunk = ChunkReader::find(buffer, "CAPA")?; // 43 41 50 41
+
+        let mut packets = vec![];
+
+        loop {
+            // Empty slices are free indexes of the pool
+            if chunk.gamma > 0 {
+                packets.push(CargoPacket::parse(&mut chunk)?);
+            }
+
+            match chunk.advance_slice()? {
+                Some(c) => chunk = c,
+                None => break
+            }
+        }
+
+        Ok(packets)
+    }
+
+    /// Parse the cargo packet information
+    pub fn parse(chunk: &mut ChunkReader) -> Result<CargoPacket, Error> {
+
+        let index = chunk.slice_idx;
+        let source_station = match chunk.fetch::<u16>()? { // source
+            INVALID_STATION => None,
+            station => Some(station as usize)
+        };
+        let source_xy = chunk.fetch::<u32>()?; // source_xy
+        let loaded_at_xy = chunk.fetch::<u32>()?; // loaded_at_xy
+        let count = chunk.fetch::<u16>()?; // count
+        let days_in_transit = chunk.fetch::<u8>()?; // days_in_transit
+        let feeder_share = Money::new(chunk.fetch::<i64>()?, currencies::GBP); // feeder_share
+        let source_type = SourceType::try_from(chunk.fetch::<u8>()?)?; // source_type
+        let source_id = chunk.fetch::<u16>()?; // source_id
+
+        Ok(CargoPacket {
+            index,
+            source_station,
+            source_xy,
+            loaded_at_xy,
+            count,
+            days_in_transit,
+            feeder_share,
+            source_type,
+            source_id
+        })
+    }
+
+    /// Check if the cargo was transferred, and a feeder already paid
+    pub fn is_transferred(&self) -> bool {
+        self.feeder_share.original != 0
+    }
+
+    /// Find the packets of a list of references
+    ///
+    /// The packets must be sorted by the index, like they are parsed.
+    pub fn find<'a>(packets: &'a [CargoPacket], indexes: &[usize]) -> Vec<&'a CargoPacket> {
+        indexes.iter()
+            .filter_map(|i| packets.binary_search_by_key(i, |p| p.index).ok())
+            .map(|i| &packets[i])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::cargo_packet::*;
+
+    #[test]
+    fn base() -> Result<(), String> {
+
+        let packets = CargoPacket::parse_list(&CAPA)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(5, packets.len());
+
+        assert_eq!(CargoPacket {
+            index: 0,
+            source_station: Some(10),
+            source_xy: 870441,
+            loaded_at_xy: 0,
+            count: 588,
+            days_in_transit: 0,
+            feeder_share: Money::new(0, currencies::GBP),
+            source_type: SourceType::Town,
+            source_id: 258
+        }, packets[0]);
+
+        assert_eq!(SourceType::Industry, packets[1].source_type);
+        assert_eq!(33, packets[1].days_in_transit);
+        assert!(!packets[1].is_transferred());
+
+        // After the free slot of the pool
+        let transferred = &packets[4];
+        assert_eq!(5, transferred.index);
+        assert_eq!(587, transferred.count);
+        assert_eq!(4161, transferred.feeder_share.original);
+        assert!(transferred.is_transferred());
+
+        let found = CargoPacket::find(&packets, &[5, 4, 1]);
+        assert_eq!(vec![5, 1], found.iter().map(|p| p.index).collect::<Vec<usize>>());
+
+        let mut buffer = CAPA.to_vec();
+        buffer[27] = 3;
+        assert!(CargoPacket::parse_list(&buffer).is_err());
+
+        Ok(())
+    }
+
+    /// Four packets, a free slot and a transferred packet of the example save
+    static CAPA: [u8; 132] = [
+        0x43, 0x41, 0x50, 0x41, 0x01, 0x19, 0x00, 0x0a, 0x00, 0x0d, 0x48, 0x29, 0x00, 0x00, 0x00, 0x00,
+        0x02, 0x4c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x02, 0x19, 0x00,
+        0x36, 0x00, 0x0c, 0xdc, 0x05, 0x00, 0x0c, 0xdc, 0x05, 0x00, 0x04, 0x21, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x92, 0x19, 0x00, 0x42, 0x00, 0x0b, 0x7c, 0x68, 0x00, 0x0b,
+        0x7c, 0x68, 0x00, 0x04, 0x0d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5a,
+        0x19, 0x00, 0x3a, 0x00, 0x0d, 0x4c, 0x11, 0x00, 0x00, 0xff, 0xff, 0x00, 0x34, 0x10, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x03, 0x89, 0x00, 0x01, 0x65, 0x01, 0x19, 0x00, 0x0a, 0x00, 0x0d, 0x48,
+        0x29, 0x00, 0x00, 0xff, 0xff, 0x02, 0x4b, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x41,
+        0x01, 0x01, 0x02, 0x00,
+    ];
+}
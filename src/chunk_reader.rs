@@ -30,7 +30,7 @@ pub struct ChunkReader {
 impl ChunkReader {
 
     /// Find the chunk
-    pub fn find(buffer: &Vec<u8>, chunk_id: &str) -> Result<ChunkReader, Error> {
+    pub fn find(buffer: &[u8], chunk_id: &str) -> Result<ChunkReader, Error> {
 
         let pos_init = match bmh::find(buffer, chunk_id.as_bytes()) {
             Some(pos) => Ok(pos),
@@ -39,13 +39,31 @@ impl ChunkReader {
 
         let (_,chunk) = buffer.split_at(pos_init);
 
-        let tp = ChunkType::try_from(chunk[4])?;
+        let tp = ChunkType::try_from(chunk[4] & 0xf)?;
+        if tp == ChunkType::Riff {
+            return Self::find_riff(chunk);
+        }
+
         let size = chunk.len();
 
-        let mut chunk = Bytes::copy_from_slice(&chunk);
+        let mut chunk = Bytes::copy_from_slice(chunk);
         chunk.advance(5); // chunk id + chunk type
 
-        let gamma = read_gamma(&mut chunk)? as usize - 1;
+        let gamma = read_gamma(&mut chunk)? as usize;
+
+        // Chunk without elements
+        if gamma == 0 {
+            return Ok(ChunkReader {
+                raw: Mutex::new(Bytes::new()),
+                gamma: 0,
+                tp,
+                reads: 0,
+                size,
+                slice_idx: 0
+            });
+        }
+
+        let gamma = gamma - 1;
         let remain_pre_idx = chunk.remaining();
         let slice_idx = if tp == ChunkType::SparseArray {
             read_gamma(&mut chunk)? as usize
@@ -64,12 +82,47 @@ impl ChunkReader {
         })
     }
 
+    /// Reader of a CH_RIFF chunk, a single block of data
+    /// without slices
+    fn find_riff(chunk: &[u8]) -> Result<ChunkReader, Error> {
+
+        if chunk.len() < 8 {
+            return Err(Error::DataCorruption(format!("Chunk header with {} bytes, expected 8", chunk.len())));
+        }
+
+        // The upper bits of the chunk type extends the 24 bits size
+        let size = ((chunk[4] as usize >> 4) << 24)
+            | ((chunk[5] as usize) << 16)
+            | ((chunk[6] as usize) << 8)
+            | chunk[7] as usize;
+
+        if size > chunk.len() - 8 {
+            return Err(Error::DataCorruption(format!("Chunk with {} bytes, but only {} bytes remaining", size, chunk.len() - 8)));
+        }
+
+        let raw = Bytes::copy_from_slice(&chunk[8..8 + size]);
+
+        Ok(ChunkReader {
+            raw: Mutex::new(raw),
+            gamma: size,
+            tp: ChunkType::Riff,
+            reads: 0,
+            size,
+            slice_idx: 0
+        })
+    }
+
     /// Advance the cursor to the next chunk slice/value
     ///
     /// More about the chunk: https://github.com/OpenTTD/OpenTTD/blob/master/docs/savegame_format.md#chunks
     pub fn advance_slice(mut self) -> Result<Option<ChunkReader>, Error> {
 
-        let mut raw = self.raw.get_mut()?;
+        // CH_RIFF chunks are made of only one slice
+        if self.tp == ChunkType::Riff {
+            return Ok(None);
+        }
+
+        let raw = self.raw.get_mut()?;
 
         let jump = self.gamma - self.reads;
         if jump > raw.remaining() {
@@ -78,14 +131,23 @@ impl ChunkReader {
 
         raw.advance(jump);
 
-        let gamma = read_gamma(&mut raw)? as i32 - 1;
+        if !raw.has_remaining() {
+            return Ok(None);
+        }
+
+        let gamma = read_gamma(raw)? as i32 - 1;
         if gamma == -1 {
             return Ok(None);
         }
 
         self.gamma = gamma as usize;
         let remain_pre_idx = raw.remaining();
-        self.slice_idx = read_gamma(&mut raw)? as usize;
+        // Only the sparse arrays have the index saved
+        self.slice_idx = if self.tp == ChunkType::SparseArray {
+            read_gamma(raw)? as usize
+        } else {
+            self.slice_idx + 1
+        };
         self.reads = remain_pre_idx - raw.remaining();
 
         Ok(Some(self))
@@ -108,14 +170,36 @@ impl ChunkReader {
     {
         let raw = self.raw.get_mut()?;
 
-        self.reads = self.reads + mem::size_of::<T>();
+        let remain_pre_fetch = raw.remaining();
+        let value = T::fetch(raw)?;
+        self.reads += remain_pre_fetch - raw.remaining();
+
+        Ok(value)
+    }
+
+    /// Fetch the next `len` values from chunk
+    pub fn fetch_array<T>(&mut self, len: usize) -> Result<Vec<T>, Error>
+        where T: ChunkDataReader<T>
+    {
+        let remaining = self.raw.get_mut()?.remaining();
+        let needed = len * mem::size_of::<T>();
+        if needed > remaining {
+            return Err(Error::DataCorruption(format!("Fetching {} bytes, but chunk only have {} bytes remaining", needed, remaining)));
+        }
+
+        let mut values = Vec::with_capacity(len);
+        for _ in 0..len {
+            values.push(self.fetch::<T>()?);
+        }
 
-        T::fetch(raw)
+        Ok(values)
     }
 }
 
 #[derive(PartialEq, Debug)]
 pub enum ChunkType {
+    /// CH_RIFF
+    Riff = 0,
     /// CH_ARRAY
     Array = 1,
     /// CH_SPARSE_ARRAY
@@ -126,8 +210,9 @@ impl TryFrom<u8> for ChunkType {
     type Error = Error;
 
     fn try_from(tp: u8) -> Result<Self, Self::Error> {
-        // We only support the CH_ARRAY and CH_SPARSE_ARRAY chunk types
+        // We only support the CH_RIFF, CH_ARRAY and CH_SPARSE_ARRAY chunk types
         match tp {
+            0 => Ok(ChunkType::Riff),
             1 => Ok(ChunkType::Array),
             2 => Ok(ChunkType::SparseArray),
             e => Err(Error::ChunkNotSupported(e))
@@ -143,6 +228,7 @@ pub trait ChunkDataReader<T> {
 /// SLE_FILE_I8
 impl ChunkDataReader<i8> for i8 {
     fn fetch(raw: &mut Bytes) -> Result<i8, Error> {
+        check_remaining(raw, 1)?;
         Ok(raw.get_i8())
     }
 }
@@ -150,6 +236,7 @@ impl ChunkDataReader<i8> for i8 {
 /// SLE_FILE_U8
 impl ChunkDataReader<u8> for u8 {
     fn fetch(raw: &mut Bytes) -> Result<u8, Error> {
+        check_remaining(raw, 1)?;
         Ok(raw.get_u8())
     }
 }
@@ -157,6 +244,7 @@ impl ChunkDataReader<u8> for u8 {
 /// SLE_FILE_I16
 impl ChunkDataReader<i16> for i16 {
     fn fetch(raw: &mut Bytes) -> Result<i16, Error> {
+        check_remaining(raw, 2)?;
         Ok(raw.get_i16())
     }
 }
@@ -164,6 +252,7 @@ impl ChunkDataReader<i16> for i16 {
 /// SLE_FILE_U16
 impl ChunkDataReader<u16> for u16 {
     fn fetch(raw: &mut Bytes) -> Result<u16, Error> {
+        check_remaining(raw, 2)?;
         Ok(raw.get_u16())
     }
 }
@@ -171,6 +260,7 @@ impl ChunkDataReader<u16> for u16 {
 /// SLE_FILE_I32
 impl ChunkDataReader<i32> for i32 {
     fn fetch(raw: &mut Bytes) -> Result<i32, Error> {
+        check_remaining(raw, 4)?;
         Ok(raw.get_i32())
     }
 }
@@ -178,6 +268,7 @@ impl ChunkDataReader<i32> for i32 {
 /// SLE_FILE_U32
 impl ChunkDataReader<u32> for u32 {
     fn fetch(raw: &mut Bytes) -> Result<u32, Error> {
+        check_remaining(raw, 4)?;
         Ok(raw.get_u32())
     }
 }
@@ -185,14 +276,18 @@ impl ChunkDataReader<u32> for u32 {
 /// SLE_VAR_I64
 impl ChunkDataReader<i64> for i64 {
     fn fetch(raw: &mut Bytes) -> Result<i64, Error> {
+        check_remaining(raw, 8)?;
         Ok(raw.get_i64())
     }
 }
 
+/// SLE_STR
 impl ChunkDataReader<String> for String {
     fn fetch(raw: &mut Bytes) -> Result<String, Error> {
 
-        let len = raw.get_u8() as usize;
+        let len = read_gamma(raw)? as usize;
+        check_remaining(raw, len)?;
+
         let strb = raw.copy_to_bytes(len);
 
         let stru = str::from_utf8(&strb)?;
@@ -201,10 +296,20 @@ impl ChunkDataReader<String> for String {
     }
 }
 
+/// Check if the chunk still has the bytes of the next value
+fn check_remaining(raw: &Bytes, len: usize) -> Result<(), Error> {
+    if len > raw.remaining() {
+        return Err(Error::DataCorruption(format!("Fetching {} bytes, but chunk only have {} bytes remaining", len, raw.remaining())));
+    }
+
+    Ok(())
+}
+
 /// Returns the gamma value
 ///
 /// More about gamma: https://github.com/OpenTTD/OpenTTD/blob/master/docs/savegame_format.md#gamma-value
 pub(crate) fn read_gamma(raw: &mut Bytes) -> Result<u32, Error> {
+    check_remaining(raw, 1)?;
     let mut r = raw.get_u8() as u32;
     if has_bit(r, 7) {
         r &= !0x80;
@@ -264,14 +369,14 @@ mod test {
 
         let chunk2 = chunk.advance_slice()
             .map_err(|e| e.to_string())?;
-        assert_eq!(true, chunk2.is_some());
+        assert!(chunk2.is_some());
         let chunk2 = chunk2.unwrap();
         assert_eq!(27, chunk2.gamma);
         assert_eq!(1, chunk2.slice_idx);
 
         let chunk3 = chunk2.advance_slice()
             .map_err(|e| e.to_string())?;
-        assert_eq!(false, chunk3.is_some());
+        assert!(chunk3.is_none());
 
         Ok(())
     }
@@ -293,7 +398,7 @@ mod test {
 
         let chunk2 = chunk.advance_slice()
             .map_err(|e| e.to_string())?;
-        assert_eq!(true, chunk2.is_some());
+        assert!(chunk2.is_some());
         let chunk2 = chunk2.unwrap();
         assert_eq!(62, chunk2.size);
         assert_eq!(27, chunk2.gamma);
@@ -303,6 +408,22 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn riff() -> Result<(), String> {
+
+        let bytes = vec![0x4d, 0x41, 0x50, 0x53, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x02, 0x00, 0x44, 0x41, 0x54, 0x45];
+
+        let mut chunk = ChunkReader::find(&bytes, "MAPS")
+            .map_err(|e| e.to_string())?;
+        assert_eq!(ChunkType::Riff, chunk.tp);
+        assert_eq!(8, chunk.size);
+        assert_eq!(vec![1024, 512], chunk.fetch_array::<u32>(2).map_err(|e| e.to_string())?);
+        assert!(chunk.fetch_array::<u8>(1).is_err());
+        assert!(chunk.advance_slice().map_err(|e| e.to_string())?.is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn gamma() -> Result<(), String> {
 
@@ -314,6 +435,6 @@ mod test {
         let mut buffer = Bytes::copy_from_slice(&[0x1c, 0x00, 0x04, 0x00]);
         assert_eq!(28, read_gamma(&mut buffer).map_err(|e| e.to_string())?);
 
-        return Ok(());
+        Ok(())
     }
 }
@@ -23,7 +23,7 @@ pub struct Company {
 impl Company {
 
     /// Parse the company information
-    pub fn parse(buffer: &Vec<u8>) -> Result<Company, Error> {
+    pub fn parse(buffer: &[u8]) -> Result<Company, Error> {
 
         let mut chunk = ChunkReader::find(buffer, "PLYR")?; // 50 4c 59 52
 
@@ -114,7 +114,7 @@ mod test {
     #[test]
     fn name_outside_plyr_chunk() -> Result<(), String> {
 
-        let company = Company::parse(&PLYR.to_vec())
+        let company = Company::parse(&PLYR)
             .map_err(|e| e.to_string())?;
 
         assert_eq!("Petfield Transport".to_string(), company.name);
@@ -128,7 +128,7 @@ mod test {
     #[test]
     fn money() -> Result<(), String> {
 
-        let company = Company::parse(&PLYR.to_vec())
+        let company = Company::parse(&PLYR)
             .map_err(|e| e.to_string())?;
 
         assert_eq!(3_647_337, company.money.value);
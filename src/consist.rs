@@ -0,0 +1,165 @@
+//! Consist related types
+//!
+//! A train is saved as a list of parts, the front engine
+//! followed by the next references to the wagons.
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/train.h
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/vehicle_base.h
+
+use std::collections::BTreeMap;
+
+use crate::error::Error;
+use crate::money::{Money, currencies};
+use crate::settings::Climate;
+use crate::vehicle::Train;
+
+/// Length of the built-in vehicles, the NewGRF ones may be shorter
+const VEHICLE_LENGTH: u32 = 8;
+
+/// Length of a tile, in the units of the vehicle length
+const TILE_SIZE: u32 = 16;
+
+/// Train with all his parts
+#[derive(Debug, PartialEq)]
+pub struct Consist<'a> {
+    /// Front engine, with the unit number and the orders
+    pub front: &'a Train,
+    /// Next parts in order, the wagons, the articulated parts
+    /// and the additional engines
+    pub parts: Vec<&'a Train>,
+}
+
+impl<'a> Consist<'a> {
+
+    /// Reconstruct the trains from their parts
+    ///
+    /// The parts must be sorted by the index, like they are parsed.
+    /// The wagons without engine, in the depots, are skipped.
+    pub fn list(trains: &'a [Train]) -> Result<Vec<Consist<'a>>, Error> {
+
+        trains.iter()
+            .filter(|t| t.is_front())
+            .map(|front| {
+                let mut parts = vec![];
+                let mut next = front.vehicle.next;
+
+                while let Some(index) = next {
+                    // A loop on the next references would never end
+                    if parts.len() >= trains.len() {
+                        return Err(Error::DataCorruption(format!("Train {} with a loop on his parts", front.vehicle.index)));
+                    }
+
+                    let part = trains.binary_search_by_key(&index, |t| t.vehicle.index)
+                        .map(|i| &trains[i])
+                        .map_err(|_| Error::DataCorruption(format!("Train {} with the unknown part {}", front.vehicle.index, index)))?;
+
+                    parts.push(part);
+                    next = part.vehicle.next;
+                }
+
+                Ok(Consist {
+                    front,
+                    parts
+                })
+            })
+            .collect()
+    }
+
+    /// All the parts, from the front engine
+    pub fn all_parts(&self) -> impl Iterator<Item = &'a Train> + '_ {
+        std::iter::once(self.front).chain(self.parts.iter().copied())
+    }
+
+    /// Length of the train, in sixteenths of a tile
+    ///
+    /// The length of the parts is not saved, each part counts as a
+    /// built-in vehicle, all of them with the full length. This is
+    /// only an approximation with the NewGRF vehicles, they may be
+    /// shorter.
+    pub fn length(&self) -> u32 {
+        (self.parts.len() as u32 + 1) * VEHICLE_LENGTH
+    }
+
+    /// Number of tiles needed by the train, like on the platforms
+    pub fn length_in_tiles(&self) -> u32 {
+        self.length().div_ceil(TILE_SIZE)
+    }
+
+    /// Capacity of the train, by the cargo id
+    pub fn capacities(&self) -> BTreeMap<u8, u32> {
+        let mut capacities = BTreeMap::new();
+
+        for part in self.all_parts().filter(|p| p.vehicle.cargo_capacity > 0) {
+            *capacities.entry(part.vehicle.cargo_type).or_insert(0) += part.vehicle.cargo_capacity as u32;
+        }
+
+        capacities
+    }
+
+    /// Amount of cargo loaded on the train, by the cargo id
+    pub fn cargo(&self) -> BTreeMap<u8, u32> {
+        let mut cargo = BTreeMap::new();
+
+        for part in self.all_parts().filter(|p| p.vehicle.cargo_count > 0) {
+            *cargo.entry(part.vehicle.cargo_type).or_insert(0) += part.vehicle.cargo_count;
+        }
+
+        cargo
+    }
+
+    /// Weight of the loaded cargo, in tonnes
+    ///
+    /// The empty weight of the parts isn't on the built-in engines
+    /// table, and the cargos unknown on the climate are skipped.
+    pub fn cargo_weight(&self, climate: Climate) -> u32 {
+        self.all_parts()
+            .filter_map(|p| Some(climate.cargo(p.vehicle.cargo_type)?.weight as u32 * p.vehicle.cargo_count / 16))
+            .sum()
+    }
+
+    /// Value of all the parts
+    pub fn value(&self) -> Money {
+        Money::new(self.all_parts().map(|p| p.vehicle.value.original).sum(), currencies::GBP)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::consist::*;
+    use crate::vehicle::Vehicles;
+    use crate::vehicle::test::{VEHS, CAPA};
+
+    #[test]
+    fn base() -> Result<(), String> {
+
+        let mut buffer = [&VEHS[..], &CAPA[..]].concat();
+
+        // The next parts of the wagon are not on the fixture
+        let vehicles = Vehicles::parse(&buffer)
+            .map_err(|e| e.to_string())?;
+        assert!(Consist::list(&vehicles.trains).is_err());
+
+        buffer[197] = 0; // next of the wagon
+
+        let vehicles = Vehicles::parse(&buffer)
+            .map_err(|e| e.to_string())?;
+        let consists = Consist::list(&vehicles.trains)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(1, consists.len());
+
+        let train = &consists[0];
+        assert_eq!(179, train.front.vehicle.index);
+        assert_eq!(vec![192], train.parts.iter().map(|p| p.vehicle.index).collect::<Vec<usize>>());
+        assert_eq!(16, train.length());
+        assert_eq!(1, train.length_in_tiles());
+        assert_eq!(BTreeMap::from([(4, 25)]), train.capacities());
+        assert_eq!(BTreeMap::from([(4, 2)]), train.cargo());
+        assert_eq!(0, train.cargo_weight(Climate::Temperate));
+        assert_eq!(5623 + 440, train.value().original);
+
+        Ok(())
+    }
+}
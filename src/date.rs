@@ -0,0 +1,90 @@
+//! Date related types
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/date.cpp
+
+use std::fmt;
+
+use crate::chunk_reader::ChunkReader;
+use crate::error::Error;
+
+/// Days between the 1st of January of the year 0 and 1970
+const DAYS_TILL_1970: i32 = 719_528;
+
+/// Game date, the number of days since the 1st of January of the year 0
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default)]
+pub struct Date(pub i32);
+
+impl Date {
+
+    /// Parse the current date of the game
+    pub fn parse(buffer: &[u8]) -> Result<Date, Error> {
+
+        let mut chunk = ChunkReader::find(buffer, "DATE")?; // 44 41 54 45
+
+        Ok(Date(chunk.fetch::<i32>()?)) // _date
+    }
+
+    /// Split the date in year, month(1..12) and day(1..31)
+    pub fn to_ymd(self) -> (i32, u8, u8) {
+        // Days to civil date, on the proleptic gregorian calendar
+        let z = self.0 - DAYS_TILL_1970 + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        (year, month as u8, day as u8)
+    }
+
+    /// Year of the date
+    pub fn year(self) -> i32 {
+        self.to_ymd().0
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (year, month, day) = self.to_ymd();
+
+        write!(f, "{:04}-{:02}-{:02}", year, month, day)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::date::Date;
+
+    #[test]
+    fn to_ymd() {
+        assert_eq!((0, 1, 1), Date(0).to_ymd());
+        assert_eq!((0, 2, 29), Date(59).to_ymd());
+        assert_eq!((1, 1, 1), Date(366).to_ymd());
+        assert_eq!((1950, 1, 1), Date(712_223).to_ymd());
+        assert_eq!((1984, 12, 30), Date(725_005).to_ymd());
+        assert_eq!(2000, Date(730_485).year());
+        assert_eq!("1950-01-01", Date(712_223).to_string());
+    }
+
+    #[test]
+    fn parse() -> Result<(), String> {
+
+        let date = Date::parse(&DATE)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(Date(729_820), date);
+        assert_eq!("1998-03-07", date.to_string());
+
+        Ok(())
+    }
+
+    /// The first field of the chunk, the current date
+    static DATE: [u8; 12] = [
+        0x44, 0x41, 0x54, 0x45, 0x00, 0x00, 0x00, 0x04, 0x00, 0x0b, 0x22, 0xdc,
+    ];
+}
@@ -0,0 +1,279 @@
+//! Engine related types
+//!
+//! The engines are the models of the vehicles, the built-in ones
+//! are found by their original id on the engines table.
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/saveload/engine_sl.cpp
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/engine.cpp
+
+use crate::bitmath::to_percent16;
+use crate::chunk_reader::ChunkReader;
+use crate::date::Date;
+use crate::error::Error;
+use crate::settings::Climate;
+use crate::table::engine::{EngineSpec, ENGINE_OFFSETS};
+use crate::tile::Owner;
+use crate::vehicle::VehicleType;
+
+/// GRF id of the built-in engines
+const INVALID_GRFID: u32 = 0xFFFFFFFF;
+
+/// Engine flags
+const ENGINE_AVAILABLE: u8 = 1 << 0;
+const ENGINE_EXCLUSIVE_PREVIEW: u8 = 1 << 1;
+
+/// Origin of an engine, the NewGRF and the id inside it
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct EngineId {
+    /// Id of the NewGRF, 0xFFFFFFFF for the built-in engines
+    pub grfid: u32,
+    /// Id of the engine inside the NewGRF, or inside the vehicle type
+    pub internal_id: u16,
+    /// Type of the vehicles
+    pub vehicle_type: VehicleType,
+}
+
+impl EngineId {
+
+    /// Parse all the engine ids, by the engine index
+    pub fn parse_list(buffer: &[u8]) -> Result<Vec<EngineId>, Error> {
+
+        let mut chunk = ChunkReader::find(buffer, "EIDS")?; // 45 49 44 53
+
+        let mut ids = vec![];
+
+        loop {
+            if chunk.gamma > 0 {
+                let grfid = chunk.fetch::<u32>()?; // grfid
+                let internal_id = chunk.fetch::<u16>()?; // internal_id
+                let vehicle_type = VehicleType::try_from(chunk.fetch::<u8>()?)?; // type
+                chunk.advance::<u8>()?; // substitute_id
+
+                ids.push(EngineId {
+                    grfid,
+                    internal_id,
+                    vehicle_type
+                });
+            }
+
+            match chunk.advance_slice()? {
+                Some(c) => chunk = c,
+                None => break
+            }
+        }
+
+        Ok(ids)
+    }
+
+    /// Id on the built-in engines table, none for the NewGRF engines
+    pub fn original_id(&self) -> Option<usize> {
+        if self.grfid != INVALID_GRFID {
+            return None;
+        }
+
+        Some(ENGINE_OFFSETS[self.vehicle_type as usize] + self.internal_id as usize)
+    }
+}
+
+/// Engine informations
+#[derive(Debug, PartialEq)]
+pub struct Engine {
+    /// Index of the engine, the engine type of the vehicles
+    pub index: usize,
+    /// Origin of the engine
+    pub id: EngineId,
+    /// Date of the introduction
+    pub intro_date: Date,
+    /// Age in months, since the introduction
+    pub age: i32,
+    /// Reliability of the new vehicles, from 0 to 65535
+    pub reliability: u16,
+    /// Max reliability, reached after the first phase
+    pub reliability_max: u16,
+    /// Engine flags
+    pub flags: u8,
+    /// Company asked to test the engine before his introduction
+    pub preview_company: Owner,
+    /// Bit mask of the companies able to buy the engine
+    pub company_avail: u16,
+    /// Custom name, empty by default
+    pub name: String,
+}
+
+impl Engine {
+
+    /// Parse all the engines, with their ids
+    pub fn parse_list(buffer: &[u8]) -> Result<Vec<Engine>, Error> {
+
+        let ids = EngineId::parse_list(buffer)?;
+
+        let mut chunk = ChunkReader::find(buffer, "ENGN")?; // 45 4e 47 4e
+
+        let mut engines = vec![];
+
+        loop {
+            if chunk.gamma > 0 {
+                engines.push(Engine::parse(&mut chunk, &ids)?);
+            }
+
+            match chunk.advance_slice()? {
+                Some(c) => chunk = c,
+                None => break
+            }
+        }
+
+        Ok(engines)
+    }
+
+    /// Parse the engine information
+    ///
+    /// The ids must be the ones of all the engines, by the engine index.
+    pub fn parse(chunk: &mut ChunkReader, ids: &[EngineId]) -> Result<Engine, Error> {
+
+        let index = chunk.slice_idx;
+        let id = *ids.get(index)
+            .ok_or_else(|| Error::DataCorruption(format!("Engine {} without id", index)))?;
+
+        let intro_date = Date(chunk.fetch::<i32>()?); // intro_date
+        let age = chunk.fetch::<i32>()?; // age
+        let reliability = chunk.fetch::<u16>()?; // reliability
+        chunk.advance::<u16>()?; // reliability_spd_dec
+        chunk.advance::<u16>()?; // reliability_start
+        let reliability_max = chunk.fetch::<u16>()?; // reliability_max
+        chunk.advance::<u16>()?; // reliability_final
+        chunk.advance::<u16>()?; // duration_phase_1
+        chunk.advance::<u16>()?; // duration_phase_2
+        chunk.advance::<u16>()?; // duration_phase_3
+        let flags = chunk.fetch::<u8>()?; // flags
+        chunk.advance::<u16>()?; // preview_asked
+        let preview_company = Owner::from(chunk.fetch::<u8>()?); // preview_company
+        chunk.advance::<u8>()?; // preview_wait
+        let company_avail = chunk.fetch::<u16>()?; // company_avail
+        chunk.advance::<u16>()?; // company_hidden
+        let name = chunk.fetch::<String>()?; // name
+
+        Ok(Engine {
+            index,
+            id,
+            intro_date,
+            age,
+            reliability,
+            reliability_max,
+            flags,
+            preview_company,
+            company_avail,
+            name
+        })
+    }
+
+    /// Reliability of the new vehicles, in percent
+    pub fn reliability_percent(&self) -> u8 {
+        to_percent16(self.reliability)
+    }
+
+    /// Check if the engine is introduced for all the companies
+    pub fn is_available(&self) -> bool {
+        self.flags & ENGINE_AVAILABLE != 0
+    }
+
+    /// Check if the engine is only available to the preview company
+    pub fn is_exclusive_preview(&self) -> bool {
+        self.flags & ENGINE_EXCLUSIVE_PREVIEW != 0
+    }
+
+    /// Check if the company can buy the engine
+    pub fn is_available_to(&self, company: u8) -> bool {
+        company < 16 && self.company_avail & (1 << company) != 0
+    }
+
+    /// Built-in engine of the climate
+    pub fn spec(&self, climate: Climate) -> Option<&'static EngineSpec> {
+        climate.engine(self.id.original_id()?)
+    }
+
+    /// Custom name, or the name of the built-in engine
+    pub fn name(&self, climate: Climate) -> Option<&str> {
+        if !self.name.is_empty() {
+            return Some(&self.name);
+        }
+
+        self.spec(climate).map(|s| s.name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::engine::*;
+
+    #[test]
+    fn base() -> Result<(), String> {
+
+        let mut engines = Engine::parse_list(&[&ENGN[..], &EIDS[..]].concat())
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(3, engines.len());
+
+        let kirby = &engines[0];
+        assert_eq!(EngineId {
+            grfid: INVALID_GRFID,
+            internal_id: 0,
+            vehicle_type: VehicleType::Train
+        }, kirby.id);
+        assert_eq!(Some(0), kirby.id.original_id());
+        assert_eq!(Date(703092), kirby.intro_date);
+        assert_eq!(863, kirby.age);
+        assert_eq!(51282, kirby.reliability);
+        assert_eq!(79, kirby.reliability_percent());
+        assert!(kirby.is_available());
+        assert!(!kirby.is_exclusive_preview());
+        assert_eq!(Owner::None, kirby.preview_company);
+        assert!(kirby.is_available_to(0));
+        assert!(!kirby.is_available_to(16));
+        assert_eq!(Some("Kirby Paul Tank (Steam)"), kirby.name(Climate::Temperate));
+        assert_eq!(None, kirby.name(Climate::SubArctic));
+
+        // Not built in the temperate climate
+        let mjs = &engines[1];
+        assert_eq!(Date(714560), mjs.intro_date);
+        assert!(!mjs.is_available_to(0));
+        assert_eq!(None, mjs.spec(Climate::Temperate));
+        assert_eq!(Some("MJS 250 (Diesel)"), mjs.name(Climate::SubTropical));
+
+        engines[1].name = String::from("Express");
+        assert_eq!(Some("Express"), engines[1].name(Climate::Temperate));
+
+        // A NewGRF engine
+        let mut buffer = EIDS.to_vec();
+        buffer[6] = 0x4e;
+        let engines = Engine::parse_list(&[&ENGN[..], &buffer[..]].concat())
+            .map_err(|e| e.to_string())?;
+        assert_eq!(None, engines[0].id.original_id());
+        assert_eq!(None, engines[0].name(Climate::Temperate));
+
+        let mut buffer = EIDS.to_vec();
+        buffer[12] = 4;
+        assert!(Engine::parse_list(&[&ENGN[..], &buffer[..]].concat()).is_err());
+
+        Ok(())
+    }
+
+    /// The first three engines
+    static ENGN: [u8; 111] = [
+        0x45, 0x4e, 0x47, 0x4e, 0x01, 0x23, 0x00, 0x0a, 0xba, 0x74, 0x00, 0x00, 0x03, 0x5f, 0xc8, 0x52,
+        0x00, 0x50, 0xb4, 0xdd, 0xc8, 0x52, 0x49, 0x38, 0x00, 0x0f, 0x01, 0x0d, 0x00, 0xf5, 0x01, 0x00,
+        0x00, 0xff, 0x00, 0xff, 0xff, 0x00, 0x00, 0x00, 0x23, 0x00, 0x0a, 0xe7, 0x40, 0x00, 0x00, 0x02,
+        0x42, 0xff, 0xea, 0x00, 0x50, 0x9b, 0x4a, 0xff, 0xea, 0x76, 0x3b, 0x00, 0x0d, 0x01, 0x15, 0x00,
+        0x83, 0x01, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x23, 0x00, 0x0a, 0xd8, 0x6a,
+        0x00, 0x00, 0x02, 0x6f, 0xed, 0x00, 0x00, 0x50, 0xb6, 0x62, 0xed, 0x00, 0x71, 0xca, 0x00, 0x1c,
+        0x01, 0xfc, 0x00, 0xc5, 0x01, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    /// The ids of the first three engines
+    static EIDS: [u8; 33] = [
+        0x45, 0x49, 0x44, 0x53, 0x01, 0x09, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x09, 0xff,
+        0xff, 0xff, 0xff, 0x00, 0x01, 0x00, 0x01, 0x09, 0xff, 0xff, 0xff, 0xff, 0x00, 0x02, 0x00, 0x02,
+        0x00,
+    ];
+}
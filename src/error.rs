@@ -26,7 +26,11 @@ pub enum Error {
     /// UTF8 Decode
     Utf8Decode(String),
     /// Type not supported yet
-    TypeNotSupportedYet(String, u16)
+    TypeNotSupportedYet(String, u16),
+    /// Errors on encode, or decode, an image
+    Image(String),
+    /// Invalid argument given to the API
+    InvalidArgument(String)
 }
 
 impl From<LzmaError> for Error {
@@ -62,6 +66,20 @@ impl From<Utf8Error> for Error {
     }
 }
 
+#[cfg(feature = "render")]
+impl From<png::EncodingError> for Error {
+    fn from(e: png::EncodingError) -> Self {
+        Self::Image(e.to_string())
+    }
+}
+
+#[cfg(feature = "render")]
+impl From<png::DecodingError> for Error {
+    fn from(e: png::DecodingError) -> Self {
+        Self::Image(e.to_string())
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", match self {
@@ -73,7 +91,9 @@ impl fmt::Display for Error {
             Error::Load(e) => e.to_string(),
             Error::DataCorruption(e) => e.to_string(),
             Error::Decompress(e) => e.to_string(),
-            Error::TypeNotSupportedYet(kind, tp) => format!("The {} is not supported by {} yet", tp, kind)
+            Error::TypeNotSupportedYet(kind, tp) => format!("The {} is not supported by {} yet", tp, kind),
+            Error::Image(e) => e.to_string(),
+            Error::InvalidArgument(e) => e.to_string()
         })
     }
 }
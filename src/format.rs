@@ -1,6 +1,6 @@
 //! Save formats and his compression filters
-///
-/// See the OpenTTD formats here: https://github.com/OpenTTD/OpenTTD/blob/master/src/saveload/saveload.cpp#L2322
+//!
+//! See the OpenTTD formats here: https://github.com/OpenTTD/OpenTTD/blob/master/src/saveload/saveload.cpp#L2322
 
 use crate::error::Error;
 
@@ -0,0 +1,354 @@
+//! Heightmap export and import
+//!
+//! Grayscale images compatible with the OpenTTD heightmap
+//! loader, with the default counter clockwise rotation.
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/heightmap.cpp
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+
+use crate::error::Error;
+use crate::map::Map;
+
+/// Bits per pixel of the PNG heightmap
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum HeightmapDepth {
+    Eight,
+    /// The OpenTTD loader only reads the 8 upper bits
+    Sixteen,
+}
+
+/// Grayscale heightmap
+#[derive(Debug, PartialEq)]
+pub struct Heightmap {
+    pub width: u32,
+    pub height: u32,
+    /// Grey levels, row by row. Zero is the sea level
+    pub levels: Vec<u8>,
+}
+
+impl Heightmap {
+
+    /// Build the heightmap of the tile heights
+    ///
+    /// The `max_height` is the max height level setting of
+    /// the game that will load the heightmap.
+    pub fn from_map(map: &Map, max_height: u8) -> Result<Heightmap, Error> {
+
+        if max_height == 0 {
+            return Err(Error::InvalidArgument("The max height must be above zero".to_string()));
+        }
+
+        let mut levels = Vec::with_capacity(map.tile_height.len());
+
+        for y in 0..map.height {
+            for x in 0..map.width {
+                // The loader mirrors the image on the X axis
+                let i = (y * map.width + (map.width - 1 - x)) as usize;
+
+                levels.push(height_to_grey(map.tile_height[i], max_height));
+            }
+        }
+
+        Ok(Heightmap {
+            width: map.width,
+            height: map.height,
+            levels
+        })
+    }
+
+    /// Build a minimal scenario map from the heightmap
+    ///
+    /// The image must have the size of a valid map, a power of two
+    /// between 64 and 4096. The borders are void, the flat tiles on the
+    /// sea level are sea and everything else is grass.
+    pub fn to_map(&self, max_height: u8) -> Result<Map, Error> {
+
+        if max_height == 0 {
+            return Err(Error::InvalidArgument("The max height must be above zero".to_string()));
+        }
+
+        for size in [self.width, self.height] {
+            if !size.is_power_of_two() || !(64..=4096).contains(&size) {
+                return Err(Error::InvalidArgument(format!("Heightmap of {}x{}, but the map sides must be a power of two between 64 and 4096", self.width, self.height)));
+            }
+        }
+
+        let (width, height) = (self.width as usize, self.height as usize);
+        let is_border = |x: usize, y: usize| x == 0 || y == 0 || x == width - 1 || y == height - 1;
+
+        let mut heights = vec![0_u8; width * height];
+        for y in 0..height {
+            for x in 0..width {
+                if !is_border(x, y) {
+                    heights[y * width + x] = grey_to_height(self.levels[y * width + (width - 1 - x)], max_height);
+                }
+            }
+        }
+
+        fix_slopes(&mut heights, width, height);
+
+        let tiles = width * height;
+        let mut map = Map {
+            width: self.width,
+            height: self.height,
+            tile_type: vec![0; tiles],
+            tile_height: heights,
+            m1: vec![0; tiles],
+            m2: vec![0; tiles],
+            m3: vec![0; tiles],
+            m4: vec![0; tiles],
+            m5: vec![0; tiles],
+            m6: vec![0; tiles],
+            m7: vec![0; tiles],
+            m8: vec![0; tiles],
+        };
+
+        for y in 0..height {
+            for x in 0..width {
+                let i = y * width + x;
+
+                if is_border(x, y) {
+                    map.tile_type[i] = 0x70; // MP_VOID
+                    continue;
+                }
+
+                let flat_sea = [i, i + 1, i + width, i + width + 1].iter()
+                    .all(|c| map.tile_height[*c] == 0);
+
+                if flat_sea {
+                    map.tile_type[i] = 0x60; // MP_WATER
+                    map.m1[i] = 0x11; // OWNER_WATER and WATER_CLASS_SEA
+                } else {
+                    map.tile_type[i] = 0x00; // MP_CLEAR
+                    map.m1[i] = 0x10; // OWNER_NONE
+                    map.m5[i] = 0x03; // CLEAR_GRASS with full density
+                }
+            }
+        }
+
+        Ok(map)
+    }
+
+    /// Encode the heightmap as PNG
+    pub fn write_png<W: Write>(&self, writer: W, depth: HeightmapDepth) -> Result<(), Error> {
+
+        let mut encoder = png::Encoder::new(writer, self.width, self.height);
+        encoder.set_color(png::ColorType::Grayscale);
+
+        let data = match depth {
+            HeightmapDepth::Eight => {
+                encoder.set_depth(png::BitDepth::Eight);
+                self.levels.clone()
+            },
+            HeightmapDepth::Sixteen => {
+                encoder.set_depth(png::BitDepth::Sixteen);
+                self.levels.iter()
+                    .flat_map(|l| (*l as u16 * 257).to_be_bytes())
+                    .collect()
+            }
+        };
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&data)?;
+
+        Ok(())
+    }
+
+    /// Save the heightmap as a PNG file
+    pub fn save_png(&self, name: String, depth: HeightmapDepth) -> Result<(), Error> {
+        let f = File::create(name)?;
+
+        self.write_png(BufWriter::new(f), depth)
+    }
+
+    /// Decode a PNG heightmap
+    ///
+    /// Like the OpenTTD loader, the colours are converted to
+    /// grey and only the 8 upper bits are used.
+    pub fn read_png<R: Read>(reader: R) -> Result<Heightmap, Error> {
+
+        let mut decoder = png::Decoder::new(reader);
+        decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+
+        let mut reader = decoder.read_info()?;
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer)?;
+
+        let channels = info.color_type.samples();
+
+        let mut levels = Vec::with_capacity((info.width * info.height) as usize);
+        for row in buffer.chunks(info.line_size).take(info.height as usize) {
+            for pixel in row.chunks(channels).take(info.width as usize) {
+                levels.push(match info.color_type {
+                    png::ColorType::Rgb | png::ColorType::Rgba => rgb_to_grey(pixel[0], pixel[1], pixel[2]),
+                    _ => pixel[0]
+                });
+            }
+        }
+
+        Ok(Heightmap {
+            width: info.width,
+            height: info.height,
+            levels
+        })
+    }
+
+    /// Load a PNG heightmap file
+    pub fn load_png(name: String) -> Result<Heightmap, Error> {
+        let f = File::open(name)?;
+
+        Self::read_png(BufReader::new(f))
+    }
+}
+
+/// Grey level of a tile height, the inverse of `grey_to_height`
+fn height_to_grey(height: u8, max_height: u8) -> u8 {
+    if height == 0 {
+        return 0;
+    }
+
+    let height = height.min(max_height) as u32;
+
+    (1 + ((height - 1) * 255).div_ceil(max_height as u32)) as u8
+}
+
+/// Tile height of a grey level, the same scale of the loader
+fn grey_to_height(grey: u8, max_height: u8) -> u8 {
+    if grey == 0 {
+        return 0;
+    }
+
+    (1 + (grey as u32 - 1) * max_height as u32 / 255) as u8
+}
+
+/// Grey level of a colour, the same formula of the loader
+fn rgb_to_grey(red: u8, green: u8, blue: u8) -> u8 {
+    ((red as u32 * 19595 + green as u32 * 38470 + blue as u32 * 7471) / 65536) as u8
+}
+
+/// Make sure that neighbour tiles differ at most one level
+fn fix_slopes(heights: &mut [u8], width: usize, height: usize) {
+
+    // Top and left edge
+    for y in 0..height {
+        for x in 0..width {
+            let mut lowest = u8::MAX;
+            if x != 0 {
+                lowest = heights[y * width + x - 1];
+            }
+            if y != 0 {
+                lowest = lowest.min(heights[(y - 1) * width + x]);
+            }
+
+            let h = &mut heights[y * width + x];
+            if *h as u32 >= lowest as u32 + 2 {
+                *h = lowest + 1;
+            }
+        }
+    }
+
+    // Bottom and right edge
+    for y in (0..height).rev() {
+        for x in (0..width).rev() {
+            let mut lowest = u8::MAX;
+            if x != width - 1 {
+                lowest = heights[y * width + x + 1];
+            }
+            if y != height - 1 {
+                lowest = lowest.min(heights[(y + 1) * width + x]);
+            }
+
+            let h = &mut heights[y * width + x];
+            if *h as u32 >= lowest as u32 + 2 {
+                *h = lowest + 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::heightmap::*;
+
+    #[test]
+    fn grey_scale() {
+        assert_eq!(0, height_to_grey(0, 30));
+        assert_eq!(248, height_to_grey(30, 30));
+        assert_eq!(248, height_to_grey(40, 30));
+        assert_eq!(255, height_to_grey(255, 255));
+
+        for max in [1, 15, 30, 255] {
+            for h in 0..=max {
+                assert_eq!(h, grey_to_height(height_to_grey(h, max), max));
+            }
+        }
+
+        assert_eq!(76, rgb_to_grey(0xff, 0, 0));
+    }
+
+    /// A 64x64 hill, with sea around
+    fn hill() -> Heightmap {
+        let mut levels = vec![0; 64 * 64];
+        for y in 24..40 {
+            for x in 20..40 {
+                levels[y * 64 + x] = 255;
+            }
+        }
+
+        Heightmap { width: 64, height: 64, levels }
+    }
+
+    #[test]
+    fn to_map() -> Result<(), String> {
+
+        let map = hill().to_map(15)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(64, map.width);
+        assert_eq!(0x70, map.tile_type[0]);
+        assert_eq!(0x60, map.tile_type[65]);
+        assert_eq!(0x00, map.tile_type[32 * 64 + 32]);
+        assert_eq!(8, map.tile_height[32 * 64 + 32]);
+
+        for y in 0..63 {
+            for x in 0..63 {
+                let h = map.tile_height[y * 64 + x] as i32;
+                assert!((h - map.tile_height[y * 64 + x + 1] as i32).abs() <= 1);
+                assert!((h - map.tile_height[(y + 1) * 64 + x] as i32).abs() <= 1);
+            }
+        }
+
+        assert!(Heightmap { width: 100, height: 64, levels: vec![0; 6400] }.to_map(15).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), String> {
+
+        let map = hill().to_map(15)
+            .map_err(|e| e.to_string())?;
+        let heightmap = Heightmap::from_map(&map, 15)
+            .map_err(|e| e.to_string())?;
+
+        for depth in [HeightmapDepth::Eight, HeightmapDepth::Sixteen] {
+            let mut buffer = vec![];
+            heightmap.write_png(&mut buffer, depth)
+                .map_err(|e| e.to_string())?;
+
+            let loaded = Heightmap::read_png(&buffer[..])
+                .map_err(|e| e.to_string())?;
+            assert_eq!(heightmap, loaded);
+
+            let loaded_map = loaded.to_map(15)
+                .map_err(|e| e.to_string())?;
+            assert_eq!(map.tile_height, loaded_map.tile_height);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,346 @@
+//! House related types
+//!
+//! The houses are only saved on the map tiles, with the
+//! index of the town that owns them.
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/house_map.h
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/town_cmd.cpp
+
+use std::collections::BTreeMap;
+
+use crate::bitmath::{gb, has_bit};
+use crate::map::Map;
+use crate::table::house::{HouseSpec, HOUSE_SPECS};
+use crate::tile::Tile;
+use crate::town::Town;
+
+/// Building stage of a completed house
+const TOWN_HOUSE_COMPLETED: u8 = 3;
+
+/// Squared radius of the centre zone while the buildings are funded
+const FUNDED_CENTRE_SQUARED_RADIUS: u32 = 25;
+
+/// Squared radius of the zones, by groups of 4 houses, up to 88 houses
+const TOWN_SQUARED_ZONE_RADIUS: [[u32; 5]; 23] = [
+    [  4,  0,  0,  0,  0], // 0
+    [ 16,  0,  0,  0,  0],
+    [ 25,  0,  0,  0,  0],
+    [ 36,  0,  0,  0,  0],
+    [ 49,  0,  4,  0,  0],
+    [ 64,  0,  4,  0,  0], // 20
+    [ 64,  0,  9,  0,  1],
+    [ 64,  0,  9,  0,  4],
+    [ 64,  0, 16,  0,  4],
+    [ 81,  0, 16,  0,  4],
+    [ 81,  0, 16,  0,  4], // 40
+    [ 81,  0, 25,  0,  9],
+    [ 81, 36, 25,  0,  9],
+    [ 81, 36, 25, 16,  9],
+    [ 81, 49,  0, 25,  9],
+    [ 81, 64,  0, 25,  9], // 60
+    [ 81, 64,  0, 36,  9],
+    [ 81, 64,  0, 36, 16],
+    [100, 81,  0, 49, 16],
+    [100, 81,  0, 49, 25],
+    [121, 81,  0, 49, 25], // 80
+    [121, 81,  0, 49, 25],
+    [121, 81,  0, 49, 36], // 88
+];
+
+/// A house tile of a town
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct House {
+    /// Position of the tile on the X axis
+    pub x: u32,
+    /// Position of the tile on the Y axis
+    pub y: u32,
+    /// Index of the town that owns the house
+    pub town: usize,
+    /// Type of the house, on the house specs
+    pub house_type: u16,
+    /// Construction stage, from 0 to 3 when completed
+    pub stage: u8,
+    /// Age of the house in years, zero until completed
+    pub age: u8,
+}
+
+impl House {
+
+    /// Decode the house of a tile
+    pub fn from_tile(tile: &Tile, x: u32, y: u32) -> Option<House> {
+        let raw = match tile {
+            Tile::House(raw) => raw,
+            _ => return None
+        };
+
+        let completed = has_bit(raw.m3 as u32, 7);
+
+        Some(House {
+            x,
+            y,
+            town: raw.m2 as usize,
+            house_type: raw.m4 as u16 | (gb(raw.m3 as u32, 6, 1) as u16) << 8,
+            stage: if completed { TOWN_HOUSE_COMPLETED } else { gb(raw.m5 as u32, 3, 2) as u8 },
+            age: if completed { raw.m5 } else { 0 },
+        })
+    }
+
+    /// Check if the construction is finished
+    pub fn is_completed(&self) -> bool {
+        self.stage == TOWN_HOUSE_COMPLETED
+    }
+
+    /// Built-in house type, none for the ones missing on the table
+    pub fn spec(&self) -> Option<&'static HouseSpec> {
+        HOUSE_SPECS.get(self.house_type as usize)
+    }
+
+    /// Inhabitants of the tile, only once the construction is finished
+    ///
+    /// None for the completed houses missing on the table.
+    pub fn population(&self) -> Option<u32> {
+        if !self.is_completed() {
+            return Some(0);
+        }
+
+        self.spec().map(|s| s.population)
+    }
+
+    /// Check if the tile is the northern one of his building
+    ///
+    /// The multi-tile buildings are counted on this tile, the houses
+    /// missing on the table count once per tile.
+    pub fn is_north_tile(&self) -> bool {
+        self.spec().is_none_or(|s| s.tiles > 0)
+    }
+
+    /// List the houses of the map, by the town index
+    pub fn list_by_town(map: &Map) -> BTreeMap<usize, Vec<House>> {
+
+        let mut houses: BTreeMap<usize, Vec<House>> = BTreeMap::new();
+
+        for y in 0..map.height {
+            for x in 0..map.width {
+                let i = (y * map.width + x) as usize;
+
+                // The type is on the bits 4..7
+                if gb(map.tile_type[i] as u32, 4, 4) != 3 {
+                    continue;
+                }
+
+                let house = map.tile(x, y)
+                    .and_then(|raw| Tile::try_from(raw).ok())
+                    .and_then(|tile| House::from_tile(&tile, x, y));
+
+                if let Some(house) = house {
+                    houses.entry(house.town).or_default().push(house);
+                }
+            }
+        }
+
+        houses
+    }
+}
+
+/// Zones of a town, used to choose the houses to build
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+pub enum HouseZone {
+    Edge,
+    Outskirts,
+    OuterSuburbs,
+    InnerSuburbs,
+    Centre,
+}
+
+impl HouseZone {
+    const ALL: [HouseZone; 5] = [
+        HouseZone::Edge,
+        HouseZone::Outskirts,
+        HouseZone::OuterSuburbs,
+        HouseZone::InnerSuburbs,
+        HouseZone::Centre,
+    ];
+}
+
+/// Extent of a town on the map
+///
+/// Like on the game, the multi-tile buildings count once, on their
+/// northern tile.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TownFootprint {
+    /// Position of the town center
+    pub center: (u32, u32),
+    /// Number of buildings
+    pub houses: usize,
+    /// Northern corner of the area with houses
+    pub min: (u32, u32),
+    /// Southern corner of the area with houses
+    pub max: (u32, u32),
+    /// Squared radius of each zone, zero for the unused zones
+    pub squared_zone_radius: [u32; 5],
+    /// Number of buildings on each zone
+    pub houses_per_zone: [usize; 5],
+    /// New buildings funded by a company, the centre zone is wider
+    pub funded: bool,
+}
+
+impl TownFootprint {
+
+    /// Compute the footprint of the town by his houses
+    pub fn new(town: &Town, houses: &[House], map: &Map) -> TownFootprint {
+
+        let center = (town.xy % map.width, town.xy / map.width);
+        let buildings = houses.iter().filter(|h| h.is_north_tile()).count();
+
+        let mut footprint = TownFootprint {
+            center,
+            houses: buildings,
+            min: center,
+            max: center,
+            squared_zone_radius: squared_zone_radius(buildings),
+            houses_per_zone: [0; 5],
+            funded: town.fund_buildings_months > 0,
+        };
+
+        for house in houses {
+            footprint.min = (footprint.min.0.min(house.x), footprint.min.1.min(house.y));
+            footprint.max = (footprint.max.0.max(house.x), footprint.max.1.max(house.y));
+
+            if !house.is_north_tile() {
+                continue;
+            }
+
+            let zone = footprint.zone(house.x, house.y);
+            footprint.houses_per_zone[zone as usize] += 1;
+        }
+
+        footprint
+    }
+
+    /// Zone of the town at the tile
+    pub fn zone(&self, x: u32, y: u32) -> HouseZone {
+        let dx = x.abs_diff(self.center.0);
+        let dy = y.abs_diff(self.center.1);
+        let distance = dx * dx + dy * dy;
+
+        if self.funded && distance <= FUNDED_CENTRE_SQUARED_RADIUS {
+            return HouseZone::Centre;
+        }
+
+        HouseZone::ALL.into_iter()
+            .rev()
+            .find(|z| distance < self.squared_zone_radius[*z as usize])
+            .unwrap_or(HouseZone::Edge)
+    }
+
+    /// Number of tiles of the area with houses
+    pub fn area(&self) -> u32 {
+        (self.max.0 - self.min.0 + 1) * (self.max.1 - self.min.1 + 1)
+    }
+
+    /// Houses by tile of the area
+    pub fn density(&self) -> f64 {
+        self.houses as f64 / self.area() as f64
+    }
+}
+
+/// Squared radius of the zones, like the game updates it
+fn squared_zone_radius(houses: usize) -> [u32; 5] {
+    if houses < 92 {
+        return TOWN_SQUARED_ZONE_RADIUS[houses / 4];
+    }
+
+    let mass = (houses / 8) as u32;
+
+    // Proportional to the square root, as it covers an area
+    [mass * 15 - 40, mass * 9 - 15, 0, mass * 5 - 5, mass * 3 + 5]
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::house::*;
+    use crate::map::RawTile;
+
+    #[test]
+    fn from_tile() -> Result<(), String> {
+
+        let tile = Tile::try_from(RawTile {
+            tile_type: 0x30,
+            m2: 7,
+            m3: 0xC0,
+            m4: 0x02,
+            m5: 12,
+            ..RawTile::default()
+        }).map_err(|e| e.to_string())?;
+
+        assert_eq!(Some(House {
+            x: 3,
+            y: 4,
+            town: 7,
+            house_type: 0x102,
+            stage: 3,
+            age: 12
+        }), House::from_tile(&tile, 3, 4));
+
+        let building = Tile::try_from(RawTile {
+            tile_type: 0x30,
+            m4: 6,
+            m5: 0x13,
+            ..RawTile::default()
+        }).map_err(|e| e.to_string())?;
+
+        let house = House::from_tile(&building, 0, 0).unwrap();
+        assert_eq!(2, house.stage);
+        assert_eq!(0, house.age);
+        assert!(!house.is_completed());
+        assert_eq!("Town houses", house.spec().unwrap().name);
+        assert_eq!(Some(0), house.population());
+        assert_eq!(Some(30), House { stage: 3, ..house }.population());
+        assert_eq!(None, House { stage: 3, house_type: 44, ..house }.population());
+        assert!(house.is_north_tile());
+        assert!(House { house_type: 20, ..house }.is_north_tile());
+        assert!(!House { house_type: 21, ..house }.is_north_tile());
+        assert!(House { house_type: 44, ..house }.is_north_tile());
+
+        let clear = Tile::try_from(RawTile::default())
+            .map_err(|e| e.to_string())?;
+        assert_eq!(None, House::from_tile(&clear, 0, 0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn zones() {
+
+        assert_eq!([4, 0, 0, 0, 0], squared_zone_radius(0));
+        assert_eq!([121, 81, 0, 49, 36], squared_zone_radius(91));
+        assert_eq!([140, 93, 0, 55, 41], squared_zone_radius(96));
+
+        let mut footprint = TownFootprint {
+            center: (10, 10),
+            houses: 24,
+            min: (8, 8),
+            max: (12, 13),
+            squared_zone_radius: squared_zone_radius(24),
+            houses_per_zone: [0; 5],
+            funded: false,
+        };
+
+        assert_eq!(HouseZone::Centre, footprint.zone(10, 10));
+        assert_eq!(HouseZone::OuterSuburbs, footprint.zone(11, 11));
+        assert_eq!(HouseZone::OuterSuburbs, footprint.zone(12, 11));
+        assert_eq!(HouseZone::Edge, footprint.zone(13, 12));
+        assert_eq!(HouseZone::Edge, footprint.zone(30, 30));
+
+        assert_eq!(30, footprint.area());
+        assert_eq!(0.8, footprint.density());
+
+        // The funded buildings are built as on the centre
+        footprint.funded = true;
+        assert_eq!(HouseZone::Centre, footprint.zone(11, 11));
+        assert_eq!(HouseZone::Centre, footprint.zone(13, 14));
+        assert_eq!(HouseZone::Edge, footprint.zone(14, 14));
+    }
+}
@@ -0,0 +1,351 @@
+//! Industry related types
+
+use crate::chunk_reader::ChunkReader;
+use crate::date::Date;
+use crate::error::Error;
+use crate::settings::Climate;
+use crate::table::industry::IndustrySpec;
+use crate::tile::Owner;
+
+/// No cargo on the slot
+const CT_INVALID: u8 = 0xFF;
+
+/// Production level where the next decrease closes the industry
+const PRODLEVEL_MINIMUM: u8 = 0x04;
+
+/// Transported part, out of 255, above it the production tends to increase
+const PERCENT_TRANSPORTED_60: u8 = 153;
+
+/// Years without production to close a processing industry
+const YEARS_WITHOUT_PRODUCTION: i32 = 5;
+
+/// Reason of an industry to close soon
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ClosureRisk {
+    /// The production is at the minimum, the next decrease closes the industry
+    MinimumProduction,
+    /// Years since the last delivery to a processing industry
+    NoProduction(i32),
+    /// Less than 60% of the cargo transported last month and this month
+    LowService,
+}
+
+/// Cargo produced by an industry
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ProducedCargo {
+    /// Cargo id, on the cargo table of the climate
+    pub cargo: u8,
+    /// Production rate, in units of 8 per production callback
+    pub rate: u8,
+    /// Cargo waiting to be moved to the stations
+    pub waiting: u16,
+    /// Cargo produced last month
+    pub last_month_production: u16,
+    /// Cargo transported last month
+    pub last_month_transported: u16,
+    /// Part of the cargo transported last month, out of 255
+    pub last_month_pct_transported: u8,
+    /// Cargo produced this month
+    pub this_month_production: u16,
+    /// Cargo transported this month
+    pub this_month_transported: u16,
+}
+
+impl ProducedCargo {
+
+    /// Percent of the cargo transported last month
+    pub fn percent_transported(&self) -> u8 {
+        ((self.last_month_pct_transported as u16 * 101) >> 8) as u8
+    }
+
+    /// Check if less than 60% of the cargo was transported last
+    /// month, and this month until now
+    fn is_underserved(&self) -> bool {
+        let this_month_pct = match self.this_month_production {
+            0 => 0,
+            p => (self.this_month_transported as u32 * 256 / p as u32).min(255) as u8
+        };
+
+        self.last_month_pct_transported <= PERCENT_TRANSPORTED_60
+            && (self.this_month_production == 0 || this_month_pct <= PERCENT_TRANSPORTED_60)
+    }
+}
+
+/// Industry informations
+#[derive(Debug, PartialEq)]
+pub struct Industry {
+    /// Index of the industry on the pool
+    pub index: usize,
+    /// Tile index of the northern tile
+    pub tile: u32,
+    /// Number of tiles on the X axis
+    pub width: u8,
+    /// Number of tiles on the Y axis
+    pub height: u8,
+    /// Type of the industry, on the industry table of the climate
+    pub industry_type: u8,
+    /// Index of the nearest town
+    pub town: Option<usize>,
+    /// Produced cargos
+    pub produced: Vec<ProducedCargo>,
+    /// Accepted cargo ids
+    pub accepted: Vec<u8>,
+    /// Company that funded, or prospected, the industry
+    pub founder: Owner,
+    /// Date of the construction
+    pub construction_date: Date,
+    /// Production level, from 4 to 128, 16 is the default
+    pub prod_level: u8,
+    /// Last year with some production
+    pub last_prod_year: i32,
+}
+
+impl Industry {
+
+    /// Parse all the industries
+    pub fn parse_list(buffer: &[u8]) -> Result<Vec<Industry>, Error> {
+
+        let mut chunk = ChunkReader::find(buffer, "INDY")?; // 49 4e 44 59
+
+        let mut industries = vec![];
+
+        loop {
+            // Empty slices are free indexes of the pool
+            if chunk.gamma > 0 {
+                industries.push(Industry::parse(&mut chunk)?);
+            }
+
+            match chunk.advance_slice()? {
+                Some(c) => chunk = c,
+                None => break
+            }
+        }
+
+        Ok(industries)
+    }
+
+    /// Parse the industry information
+    pub fn parse(chunk: &mut ChunkReader) -> Result<Industry, Error> {
+
+        // Fields from https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/saveload/industry_sl.cpp
+
+        let index = chunk.slice_idx;
+        let tile = chunk.fetch::<u32>()?; // location.tile
+        let width = chunk.fetch::<u8>()?; // location.w
+        let height = chunk.fetch::<u8>()?; // location.h
+        let town = (chunk.fetch::<u32>()? as usize).checked_sub(1); // town
+
+        let produced_cargo = [chunk.fetch::<u8>()?, chunk.fetch::<u8>()?]; // produced_cargo
+        for _ in 0..3 {
+            chunk.advance::<u16>()?; // incoming_cargo_waiting
+        }
+        let waiting = [chunk.fetch::<u16>()?, chunk.fetch::<u16>()?]; // produced_cargo_waiting
+        let rate = [chunk.fetch::<u8>()?, chunk.fetch::<u8>()?]; // production_rate
+
+        let mut accepted = vec![];
+        for _ in 0..3 {
+            let cargo = chunk.fetch::<u8>()?; // accepts_cargo
+            if cargo != CT_INVALID {
+                accepted.push(cargo);
+            }
+        }
+
+        let prod_level = chunk.fetch::<u8>()?; // prod_level
+        let this_month_production = [chunk.fetch::<u16>()?, chunk.fetch::<u16>()?]; // this_month_production
+        let this_month_transported = [chunk.fetch::<u16>()?, chunk.fetch::<u16>()?]; // this_month_transported
+        let last_month_pct_transported = [chunk.fetch::<u8>()?, chunk.fetch::<u8>()?]; // last_month_pct_transported
+        let last_month_production = [chunk.fetch::<u16>()?, chunk.fetch::<u16>()?]; // last_month_production
+        let last_month_transported = [chunk.fetch::<u16>()?, chunk.fetch::<u16>()?]; // last_month_transported
+
+        chunk.advance::<u16>()?; // counter
+
+        let industry_type = chunk.fetch::<u8>()?; // type
+        chunk.advance::<u8>()?; // owner
+        chunk.advance::<u8>()?; // random_colour
+        let last_prod_year = chunk.fetch::<i32>()?; // last_prod_year
+        chunk.advance::<u8>()?; // was_cargo_delivered
+
+        let founder = Owner::from(chunk.fetch::<u8>()?); // founder
+        let construction_date = Date(chunk.fetch::<i32>()?); // construction_date
+
+        let produced = (0..2)
+            .filter(|i| produced_cargo[*i] != CT_INVALID)
+            .map(|i| ProducedCargo {
+                cargo: produced_cargo[i],
+                rate: rate[i],
+                waiting: waiting[i],
+                last_month_production: last_month_production[i],
+                last_month_transported: last_month_transported[i],
+                last_month_pct_transported: last_month_pct_transported[i],
+                this_month_production: this_month_production[i],
+                this_month_transported: this_month_transported[i],
+            })
+            .collect();
+
+        Ok(Industry {
+            index,
+            tile,
+            width,
+            height,
+            industry_type,
+            town,
+            produced,
+            accepted,
+            founder,
+            construction_date,
+            prod_level,
+            last_prod_year
+        })
+    }
+
+    /// Reasons of the industry to close soon
+    ///
+    /// The save only keeps the last month and this month, so the
+    /// service is checked only on these two months.
+    pub fn closure_risks(&self, today: Date, climate: Climate) -> Vec<ClosureRisk> {
+
+        let mut risks = vec![];
+
+        // Original economy decreases the level, smooth economy the rates
+        let is_primary = self.accepted.is_empty() && !self.produced.is_empty();
+        if self.prod_level <= PRODLEVEL_MINIMUM
+            || (is_primary && self.produced.iter().all(|p| p.rate <= 1)) {
+            risks.push(ClosureRisk::MinimumProduction);
+        }
+
+        // Only the processing industries close without deliveries
+        let years = today.year() - self.last_prod_year;
+        let processing = self.spec(climate).is_some_and(|s| s.processing);
+        if processing && years >= YEARS_WITHOUT_PRODUCTION {
+            risks.push(ClosureRisk::NoProduction(years));
+        }
+
+        let producing = self.produced.iter()
+            .filter(|p| p.last_month_production > 0 || p.this_month_production > 0)
+            .collect::<Vec<&ProducedCargo>>();
+        if !producing.is_empty() && producing.iter().all(|p| p.is_underserved()) {
+            risks.push(ClosureRisk::LowService);
+        }
+
+        risks
+    }
+
+    /// Default industry type of the climate
+    pub fn spec(&self, climate: Climate) -> Option<&'static IndustrySpec> {
+        climate.industry(self.industry_type)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::industry::*;
+
+    #[test]
+    fn base() -> Result<(), String> {
+
+        let industries = Industry::parse_list(&INDY)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(3, industries.len());
+
+        assert_eq!(Industry {
+            index: 0,
+            tile: 613390,
+            width: 4,
+            height: 4,
+            industry_type: 4,
+            town: Some(428),
+            produced: vec![ProducedCargo {
+                cargo: 5,
+                rate: 0,
+                waiting: 0,
+                last_month_production: 0,
+                last_month_transported: 0,
+                last_month_pct_transported: 0,
+                this_month_production: 0,
+                this_month_transported: 0
+            }],
+            accepted: vec![3],
+            founder: Owner::None,
+            construction_date: Date(725005),
+            prod_level: 16,
+            last_prod_year: 1984
+        }, industries[0]);
+
+        // Power station, without production
+        assert_eq!(2, industries[1].index);
+        assert!(industries[1].produced.is_empty());
+        assert_eq!(1950, industries[1].construction_date.year());
+
+        // Bank
+        assert_eq!(12, industries[2].industry_type);
+        assert_eq!("Bank", industries[2].spec(Climate::Temperate).unwrap().name);
+        assert_eq!(7, industries[2].produced[0].rate);
+        assert_eq!(56, industries[2].produced[0].last_month_production);
+
+        Ok(())
+    }
+
+    #[test]
+    fn closure_risks() -> Result<(), String> {
+
+        let mut industries = Industry::parse_list(&INDY)
+            .map_err(|e| e.to_string())?;
+
+        let today = Date(729820);
+        let climate = Climate::Temperate;
+
+        // Refinery without deliveries since 1984
+        assert_eq!(vec![ClosureRisk::NoProduction(14)], industries[0].closure_risks(today, climate));
+
+        // Power stations never produce, and never close that way
+        assert!(industries[1].closure_risks(today, climate).is_empty());
+
+        // Bank without service
+        let bank = &mut industries[2];
+        assert_eq!(14, bank.produced[0].this_month_production);
+        assert_eq!(vec![ClosureRisk::LowService], bank.closure_risks(today, climate));
+
+        bank.produced[0].last_month_pct_transported = 200;
+        assert_eq!(78, bank.produced[0].percent_transported());
+        assert!(bank.closure_risks(today, climate).is_empty());
+
+        bank.produced[0].last_month_pct_transported = 100;
+        bank.produced[0].this_month_transported = 10;
+        assert!(bank.closure_risks(today, climate).is_empty());
+
+        bank.prod_level = 4;
+        assert_eq!(vec![ClosureRisk::MinimumProduction], bank.closure_risks(today, climate));
+
+        Ok(())
+    }
+
+    #[test]
+    fn truncated() {
+
+        let mut buffer = INDY.to_vec();
+        buffer.truncate(40);
+
+        assert!(Industry::parse_list(&buffer).is_err());
+    }
+
+    /// Three industries, with a free index after the first
+    static INDY: [u8; 232] = [
+        0x49, 0x4e, 0x44, 0x59, 0x01, 0x4b, 0x00, 0x09, 0x5c, 0x0e, 0x04, 0x04, 0x00, 0x00, 0x01, 0xad,
+        0x05, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xff,
+        0xff, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x94, 0x93, 0x04, 0x10, 0x04, 0x00, 0x00, 0x07, 0xc0, 0x00, 0x10, 0x00,
+        0x0b, 0x10, 0x0d, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x14,
+        0x01, 0x4b, 0x00, 0x09, 0x2f, 0x4a, 0x03, 0x03, 0x00, 0x00, 0x00, 0xca, 0xff, 0xff, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xff, 0xff, 0x10, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x2b, 0x4c, 0x01, 0x10, 0x0e, 0x00, 0x00, 0x07, 0x9e, 0x00, 0x10, 0x00, 0x0a, 0xde, 0x1f, 0x02,
+        0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x92, 0xb2, 0x4b, 0x00, 0x07, 0x55,
+        0xa6, 0x02, 0x01, 0x00, 0x00, 0x00, 0xf2, 0x0a, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x0a, 0xff, 0xff, 0x10, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x38, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x34, 0x4e, 0x0c, 0x10, 0x0a,
+        0x00, 0x00, 0x07, 0xce, 0x00, 0x10, 0x00, 0x0a, 0xe8, 0xbe, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x16, 0x9c, 0x00,
+    ];
+}
@@ -13,6 +13,25 @@ mod table;
 mod seeds;
 mod money;
 mod vehicle;
+mod consist;
+mod engine;
+mod order;
+mod timetable;
+mod town;
+mod house;
+mod industry;
+mod station;
+mod cargo_packet;
+mod link_graph;
+mod date;
+mod settings;
+mod map;
+mod tile;
+#[cfg(feature = "render")]
+mod render;
+#[cfg(feature = "render")]
+mod heightmap;
+mod preview;
 mod bitmath;
 #[cfg(test)]
 pub mod tests;
@@ -20,6 +39,30 @@ pub mod tests;
 pub use format::Format;
 pub use save::SaveGame;
 pub use company::Company;
-pub use vehicle::{Vehicles, Train};
+pub use vehicle::{Vehicles, Vehicle, VehicleType, Train, ForceProceed, RoadVehicle, RoadVehicleState, RoadType, Ship, ShipState, Aircraft, AircraftState};
+pub use consist::Consist;
+pub use engine::{Engine, EngineId};
+pub use timetable::{Timetable, TimetableEntry, TimetableTime, VehicleTimetable};
+pub use order::{Order, OrderList, OrderType, OrderNonStop, OrderLoad, OrderUnload};
+pub use town::{Town, TownRating, AuthorityRating, TransportedCargo, ReceivedCargo};
+pub use house::{House, HouseZone, TownFootprint};
+pub use industry::{Industry, ProducedCargo, ClosureRisk};
+pub use station::{Station, StationFacilities, GoodsEntry, Airport, AirportType, AirportRotation};
+pub use cargo_packet::{CargoPacket, SourceType};
+pub use link_graph::{LinkGraph, LinkGraphNode, LinkGraphEdge, LinkGraphJob, LinkGraphSchedule};
+pub use date::Date;
+pub use settings::{GameSettings, Climate};
+pub use table::cargo::CargoSpec;
+pub use table::industry::IndustrySpec;
+pub use table::airport::AirportSpec;
+pub use table::house::HouseSpec;
+pub use table::engine::EngineSpec;
+pub use map::{Map, RawTile};
+pub use tile::{Tile, Owner, WaterClass};
+#[cfg(feature = "render")]
+pub use render::{MapImage, RenderMode};
+#[cfg(feature = "render")]
+pub use heightmap::{Heightmap, HeightmapDepth};
+pub use preview::{MapPreview, PreviewCell, Region};
 pub use error::Error;
 pub use money::{Money, Currency, currencies};
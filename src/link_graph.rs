@@ -0,0 +1,479 @@
+//! Cargo distribution related types
+//!
+//! Each cargo with the cargo distribution has one link graph per
+//! connected network. The graphs are copied on the jobs while the
+//! flows are computed, in the background of the game.
+//!
+//! The travel time of the links isn't saved on this version.
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/saveload/linkgraph_sl.cpp
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/linkgraph/linkgraph.h
+
+use std::fmt::Write;
+
+use crate::chunk_reader::ChunkReader;
+use crate::date::Date;
+use crate::error::Error;
+use crate::station::Station;
+
+/// End of the list of edges of a node
+const INVALID_NODE: u16 = 0xFFFF;
+
+/// Date never reached, like the links never used
+const INVALID_DATE: i32 = -1;
+
+/// A station of a link graph
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct LinkGraphNode {
+    /// Index of the station
+    pub station: usize,
+    /// Tile of the station
+    pub xy: u32,
+    /// Cargo supplied to the station, during the last months
+    pub supply: u32,
+    /// Acceptance of the station, zero when not accepted
+    pub demand: u32,
+    /// Last update of the supply
+    pub last_update: Option<Date>,
+}
+
+/// A link between two stations of a link graph
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct LinkGraphEdge {
+    /// Node of the departure station
+    pub from: usize,
+    /// Node of the arrival station
+    pub to: usize,
+    /// Capacity of the vehicles on the link, by month
+    pub capacity: u32,
+    /// Cargo transported on the link, by month
+    pub usage: u32,
+    /// Last time a vehicle without restricted flows used the link
+    pub last_unrestricted_update: Option<Date>,
+    /// Last time a vehicle with restricted flows used the link
+    pub last_restricted_update: Option<Date>,
+}
+
+impl LinkGraphEdge {
+
+    /// Part of the capacity used, in percent
+    pub fn saturation(&self) -> u32 {
+        if self.capacity == 0 {
+            return 0;
+        }
+
+        (self.usage as u64 * 100 / self.capacity as u64) as u32
+    }
+}
+
+/// Link graph of a cargo
+#[derive(Debug, PartialEq)]
+pub struct LinkGraph {
+    /// Index of the link graph on the pool
+    pub index: usize,
+    /// Cargo id, on the cargos of the climate
+    pub cargo: u8,
+    /// Last time the supplies were compressed
+    pub last_compression: Date,
+    /// Stations of the graph
+    pub nodes: Vec<LinkGraphNode>,
+    /// Links between the stations, by node indexes
+    pub edges: Vec<LinkGraphEdge>,
+}
+
+impl LinkGraph {
+
+    /// Parse all the link graphs
+    pub fn parse_list(buffer: &[u8]) -> Result<Vec<LinkGraph>, Error> {
+
+        let mut chunk = ChunkReader::find(buffer, "LGRP")?; // 4c 47 52 50
+
+        let mut graphs = vec![];
+
+        loop {
+            // Empty slices are free indexes of the pool
+            if chunk.gamma > 0 {
+                let index = chunk.slice_idx;
+                graphs.push(LinkGraph::parse(&mut chunk, index)?);
+            }
+
+            match chunk.advance_slice()? {
+                Some(c) => chunk = c,
+                None => break
+            }
+        }
+
+        Ok(graphs)
+    }
+
+    /// Parse the link graph information
+    pub fn parse(chunk: &mut ChunkReader, index: usize) -> Result<LinkGraph, Error> {
+
+        let last_compression = Date(chunk.fetch::<i32>()?); // last_compression
+        let num_nodes = chunk.fetch::<u16>()?; // _num_nodes
+        let cargo = chunk.fetch::<u8>()?; // cargo
+
+        let mut nodes = vec![];
+        let mut edges = vec![];
+
+        for from in 0..num_nodes {
+            nodes.push(LinkGraphNode {
+                xy: chunk.fetch::<u32>()?, // xy
+                supply: chunk.fetch::<u32>()?, // supply
+                demand: chunk.fetch::<u32>()?, // demand
+                station: chunk.fetch::<u16>()? as usize, // station
+                last_update: valid_date(chunk.fetch::<i32>()?), // last_update
+            });
+
+            // The edges of a node are a list, starting on the edge to itself
+            let mut to = from;
+            loop {
+                let capacity = chunk.fetch::<u32>()?; // capacity
+                let usage = chunk.fetch::<u32>()?; // usage
+                let last_unrestricted_update = valid_date(chunk.fetch::<i32>()?); // last_unrestricted_update
+                let last_restricted_update = valid_date(chunk.fetch::<i32>()?); // last_restricted_update
+                let next_edge = chunk.fetch::<u16>()?; // next_edge
+
+                if to != from {
+                    edges.push(LinkGraphEdge {
+                        from: from as usize,
+                        to: to as usize,
+                        capacity,
+                        usage,
+                        last_unrestricted_update,
+                        last_restricted_update
+                    });
+                }
+
+                if next_edge == INVALID_NODE {
+                    break;
+                }
+                if next_edge >= num_nodes || next_edge == from {
+                    return Err(Error::DataCorruption(format!("Link graph {} with an edge to the node {}", index, next_edge)));
+                }
+
+                to = next_edge;
+            }
+        }
+
+        Ok(LinkGraph {
+            index,
+            cargo,
+            last_compression,
+            nodes,
+            edges
+        })
+    }
+
+    /// Name of the station of a node, with the station index when unknown
+    fn node_name(&self, node: &LinkGraphNode, stations: &[Station]) -> String {
+        stations.iter()
+            .find(|s| s.index == node.station)
+            .map(|s| s.name.clone())
+            .unwrap_or_else(|| format!("Station {}", node.station))
+    }
+
+    /// Export the graph on the DOT language of Graphviz
+    pub fn to_dot(&self, stations: &[Station]) -> String {
+
+        let mut dot = String::new();
+
+        let _ = writeln!(dot, "digraph linkgraph_{} {{", self.index);
+        let _ = writeln!(dot, "    label=\"Cargo {}\";", self.cargo);
+
+        for node in &self.nodes {
+            let _ = writeln!(dot, "    {} [label=\"{}\", supply={}, demand={}];",
+                node.station, escape_dot(&self.node_name(node, stations)), node.supply, node.demand);
+        }
+
+        for edge in &self.edges {
+            let _ = writeln!(dot, "    {} -> {} [label=\"{}/{}\", capacity={}, usage={}];",
+                self.nodes[edge.from].station, self.nodes[edge.to].station,
+                edge.usage, edge.capacity, edge.capacity, edge.usage);
+        }
+
+        dot.push_str("}\n");
+
+        dot
+    }
+
+    /// Export the graph on the GraphML format
+    pub fn to_graphml(&self, stations: &[Station]) -> String {
+
+        let mut xml = String::new();
+
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        xml.push_str("  <key id=\"name\" for=\"node\" attr.name=\"name\" attr.type=\"string\"/>\n");
+        xml.push_str("  <key id=\"supply\" for=\"node\" attr.name=\"supply\" attr.type=\"long\"/>\n");
+        xml.push_str("  <key id=\"demand\" for=\"node\" attr.name=\"demand\" attr.type=\"long\"/>\n");
+        xml.push_str("  <key id=\"capacity\" for=\"edge\" attr.name=\"capacity\" attr.type=\"long\"/>\n");
+        xml.push_str("  <key id=\"usage\" for=\"edge\" attr.name=\"usage\" attr.type=\"long\"/>\n");
+        let _ = writeln!(xml, "  <graph id=\"linkgraph_{}\" edgedefault=\"directed\">", self.index);
+
+        for node in &self.nodes {
+            let _ = writeln!(xml, "    <node id=\"{}\">", node.station);
+            let _ = writeln!(xml, "      <data key=\"name\">{}</data>", escape_xml(&self.node_name(node, stations)));
+            let _ = writeln!(xml, "      <data key=\"supply\">{}</data>", node.supply);
+            let _ = writeln!(xml, "      <data key=\"demand\">{}</data>", node.demand);
+            xml.push_str("    </node>\n");
+        }
+
+        for edge in &self.edges {
+            let _ = writeln!(xml, "    <edge source=\"{}\" target=\"{}\">",
+                self.nodes[edge.from].station, self.nodes[edge.to].station);
+            let _ = writeln!(xml, "      <data key=\"capacity\">{}</data>", edge.capacity);
+            let _ = writeln!(xml, "      <data key=\"usage\">{}</data>", edge.usage);
+            xml.push_str("    </edge>\n");
+        }
+
+        xml.push_str("  </graph>\n");
+        xml.push_str("</graphml>\n");
+
+        xml
+    }
+}
+
+/// Computation of the flows of a link graph
+#[derive(Debug, PartialEq)]
+pub struct LinkGraphJob {
+    /// Index of the job on the pool
+    pub index: usize,
+    /// Date when the flows are merged on the game
+    pub join_date: Date,
+    /// Copy of the link graph, when the job started
+    pub graph: LinkGraph,
+}
+
+impl LinkGraphJob {
+
+    /// Parse all the running jobs
+    pub fn parse_list(buffer: &[u8]) -> Result<Vec<LinkGraphJob>, Error> {
+
+        let mut chunk = ChunkReader::find(buffer, "LGRJ")?; // 4c 47 52 4a
+
+        let mut jobs = vec![];
+
+        loop {
+            // Empty slices are free indexes of the pool
+            if chunk.gamma > 0 {
+                jobs.push(LinkGraphJob::parse(&mut chunk)?);
+            }
+
+            match chunk.advance_slice()? {
+                Some(c) => chunk = c,
+                None => break
+            }
+        }
+
+        Ok(jobs)
+    }
+
+    /// Parse the job information
+    pub fn parse(chunk: &mut ChunkReader) -> Result<LinkGraphJob, Error> {
+
+        let index = chunk.slice_idx;
+
+        // Copy of the linkgraph settings
+        chunk.advance::<u16>()?; // recalc_interval
+        chunk.advance::<u16>()?; // recalc_time
+        for _ in 0..4 {
+            chunk.advance::<u8>()?; // distribution_pax, mail, armoured and default
+        }
+        chunk.advance::<u8>()?; // accuracy
+        chunk.advance::<u8>()?; // demand_distance
+        chunk.advance::<u8>()?; // demand_size
+        chunk.advance::<u8>()?; // short_path_saturation
+
+        let join_date = Date(chunk.fetch::<i32>()?); // join_date
+        let link_graph = chunk.fetch::<u16>()? as usize; // link_graph.index
+
+        Ok(LinkGraphJob {
+            index,
+            join_date,
+            graph: LinkGraph::parse(chunk, link_graph)?
+        })
+    }
+}
+
+/// Order of the link graphs to compute
+#[derive(Debug, PartialEq)]
+pub struct LinkGraphSchedule {
+    /// Link graphs waiting for a job
+    pub scheduled: Vec<usize>,
+    /// Jobs running
+    pub running: Vec<usize>,
+}
+
+impl LinkGraphSchedule {
+
+    /// Parse the schedule of the link graphs
+    pub fn parse(buffer: &[u8]) -> Result<LinkGraphSchedule, Error> {
+
+        let mut chunk = ChunkReader::find(buffer, "LGRS")?; // 4c 47 52 53
+
+        let scheduled = parse_references(&mut chunk)?; // schedule
+        let running = parse_references(&mut chunk)?; // running
+
+        Ok(LinkGraphSchedule {
+            scheduled,
+            running
+        })
+    }
+}
+
+/// List of references to a pool, saved as the index plus one
+fn parse_references(chunk: &mut ChunkReader) -> Result<Vec<usize>, Error> {
+    let count = chunk.fetch::<u32>()?;
+
+    Ok(chunk.fetch_array::<u32>(count as usize)?
+        .into_iter()
+        .filter_map(|r| (r as usize).checked_sub(1))
+        .collect())
+}
+
+/// Dates of the graph, unset until the first update
+fn valid_date(date: i32) -> Option<Date> {
+    match date {
+        INVALID_DATE => None,
+        date => Some(Date(date))
+    }
+}
+
+/// Escape a string of the DOT language
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Escape a text of a XML document
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::link_graph::*;
+
+    #[test]
+    fn base() -> Result<(), String> {
+
+        let graphs = LinkGraph::parse_list(&LINK)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(2, graphs.len());
+
+        let mail = &graphs[0];
+        assert_eq!(4, mail.cargo);
+        assert_eq!(Date(729609), mail.last_compression);
+        assert_eq!(LinkGraphNode {
+            station: 58,
+            xy: 871441,
+            supply: 0,
+            demand: 12,
+            last_update: None
+        }, mail.nodes[0]);
+        assert_eq!(vec![63, 66], mail.nodes[1..].iter().map(|n| n.station).collect::<Vec<usize>>());
+
+        assert_eq!(vec![LinkGraphEdge {
+            from: 1,
+            to: 0,
+            capacity: 282,
+            usage: 188,
+            last_unrestricted_update: Some(Date(729820)),
+            last_restricted_update: None
+        }, LinkGraphEdge {
+            from: 2,
+            to: 0,
+            capacity: 344,
+            usage: 0,
+            last_unrestricted_update: Some(Date(729820)),
+            last_restricted_update: None
+        }], mail.edges);
+        assert_eq!(66, mail.edges[0].saturation());
+
+        assert_eq!(6, graphs[1].cargo);
+        assert_eq!(1, graphs[1].edges.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn jobs() -> Result<(), String> {
+
+        let jobs = LinkGraphJob::parse_list(&LINK)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(1, jobs.len());
+        assert_eq!(Date(729820), jobs[0].join_date);
+        assert_eq!(7, jobs[0].graph.index);
+        assert_eq!(9, jobs[0].graph.cargo);
+        assert_eq!(2, jobs[0].graph.nodes.len());
+
+        let schedule = LinkGraphSchedule::parse(&LINK)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(vec![1, 2, 5], schedule.scheduled);
+        assert_eq!(vec![2, 0, 4, 3, 1], schedule.running);
+
+        Ok(())
+    }
+
+    #[test]
+    fn export() -> Result<(), String> {
+
+        let graphs = LinkGraph::parse_list(&LINK)
+            .map_err(|e| e.to_string())?;
+
+        let dot = graphs[1].to_dot(&[]);
+        assert!(dot.starts_with("digraph linkgraph_1 {\n"));
+        assert!(dot.contains("    58 [label=\"Station 58\", supply=0, demand=12];\n"));
+        assert!(dot.contains("    63 -> 58 [label=\"236/365\", capacity=365, usage=236];\n"));
+        assert!(dot.ends_with("}\n"));
+
+        let xml = graphs[1].to_graphml(&[]);
+        assert!(xml.contains("<node id=\"63\">"));
+        assert!(xml.contains("<edge source=\"63\" target=\"58\">"));
+        assert!(xml.ends_with("</graphml>\n"));
+
+        assert_eq!("A \\\"B\\\"", escape_dot("A \"B\""));
+        assert_eq!("A &amp; &lt;B&gt;", escape_xml("A & <B>"));
+
+        Ok(())
+    }
+
+    /// Two link graphs, a job and the schedule of the example save
+    static LINK: [u8; 427] = [
+        0x4c, 0x47, 0x52, 0x50, 0x01, 0x80, 0x98, 0x00, 0x0b, 0x22, 0x09, 0x00, 0x03, 0x04, 0x00, 0x0d,
+        0x4c, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x3a, 0xff, 0xff, 0xff, 0xff,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0x00, 0x0e, 0x78, 0x18, 0x00, 0x00, 0x00, 0xc3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f,
+        0x00, 0x0b, 0x22, 0xdb, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x01, 0x1a, 0x00, 0x00, 0x00, 0xbc, 0x00, 0x0b,
+        0x22, 0xdc, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x0b, 0x7c, 0x68, 0x00, 0x00, 0x01, 0x7c,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x42, 0x00, 0x0b, 0x22, 0xdb, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x01, 0x58,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x0b, 0x22, 0xdc, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x62, 0x00,
+        0x0b, 0x22, 0x09, 0x00, 0x02, 0x06, 0x00, 0x0d, 0x4c, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x0c, 0x00, 0x3a, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x0e, 0x78, 0x18, 0x00, 0x00,
+        0x00, 0xe7, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0x00, 0x0b, 0x22, 0xdb, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0x6d, 0x00, 0x00, 0x00, 0xec, 0x00, 0x0b, 0x22, 0xdc, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0x00, 0x4c, 0x47, 0x52, 0x4a, 0x01, 0x74, 0x00, 0x04, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x10,
+        0x64, 0x64, 0x50, 0x00, 0x0b, 0x22, 0xdc, 0x00, 0x07, 0x00, 0x0b, 0x22, 0x40, 0x00, 0x02, 0x09,
+        0x00, 0x0d, 0x4c, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x3a, 0xff, 0xff,
+        0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0x00, 0x0d, 0x90, 0x14, 0x00, 0x00, 0x01, 0x60, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x11, 0x00, 0x0b, 0x22, 0xbe, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x02, 0x53, 0x00, 0x00, 0x01, 0xb8,
+        0x00, 0x0b, 0x22, 0xbe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x4c, 0x47, 0x52, 0x53, 0x00,
+        0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x02,
+    ];
+}
@@ -5,7 +5,7 @@ use tabled::{Style, Table, Tabled};
 
 use ropenttd::*;
 
-#[cmd_group(commands = [company])]
+#[cmd_group(commands = [company, map, timetable])]
 fn main() -> Result<(), Error> {}
 
 /// Details the main/default company
@@ -35,6 +35,105 @@ fn company(
     Ok(())
 }
 
+/// Preview the map on the terminal
+#[subcmd]
+fn map(
+    file: String,
+    /// Area of the map, as x,y,width,height
+    #[opt(long)]
+    region: Option<String>,
+    /// Number of columns, by default the terminal width
+    #[opt(long)]
+    columns: Option<u32>,
+) -> Result<(), Error> {
+    let mut sv = SaveGame::load_from_file(file)?;
+
+    let map = sv.map()?;
+
+    let region = match region {
+        Some(r) => r.parse::<Region>()?,
+        None => Region::whole(&map)
+    };
+
+    let columns = columns
+        .or_else(|| std::env::var("COLUMNS").ok().and_then(|c| c.parse().ok()))
+        .unwrap_or(80);
+
+    let preview = MapPreview::render(&map, region, columns)?;
+
+    print!("{}", preview);
+
+    Ok(())
+}
+
+/// List the vehicles on their timetables, the late ones first
+#[subcmd]
+fn timetable(
+    file: String,
+    /// Only list the late vehicles
+    #[opt(long)]
+    late: bool,
+) -> Result<(), Error> {
+    let mut sv = SaveGame::load_from_file(file)?;
+
+    let timetables = sv.timetables()?;
+    let engines = sv.engines()?;
+    let climate = sv.settings()?.climate;
+
+    let mut data = vec![];
+    for timetable in timetables.iter() {
+        for vehicle in timetable.vehicles.iter().filter(|v| !late || v.is_late()) {
+            let status = if vehicle.is_late() {
+                "LATE"
+            } else if vehicle.is_early() {
+                "early"
+            } else if vehicle.started {
+                "on time"
+            } else {
+                "not started"
+            };
+
+            let engine = engines.get(vehicle.engine_type as usize)
+                .and_then(|e| e.name(climate))
+                .map(|n| n.to_string())
+                .unwrap_or_else(|| format!("Engine {}", vehicle.engine_type));
+
+            data.push(TimetablePrintable {
+                vehicle: format!("{:?} {}", vehicle.vehicle_type, vehicle.unit_number),
+                engine,
+                order_list: timetable.order_list,
+                orders: timetable.entries.len(),
+                current_order: vehicle.current_entry.map(|e| (e + 1).to_string()).unwrap_or_default(),
+                start_date: vehicle.start_date.map(|d| d.to_string()).unwrap_or_default(),
+                round_days: timetable.estimated_duration_days(),
+                lateness_days: vehicle.lateness_days(),
+                status: status.to_string(),
+            });
+        }
+    }
+
+    data.sort_by_key(|d| -d.lateness_days);
+
+    let printable = Table::new(data).with(Style::blank());
+
+    println!("{}", printable);
+
+    Ok(())
+}
+
+#[derive(Tabled)]
+struct TimetablePrintable {
+    pub vehicle: String,
+    pub engine: String,
+    pub order_list: usize,
+    pub orders: usize,
+    pub current_order: String,
+    pub start_date: String,
+    pub round_days: u32,
+    pub lateness_days: i32,
+    pub status: String,
+}
+
 #[derive(Tabled)]
 struct CompanyPrintable {
     pub name: String,
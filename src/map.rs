@@ -0,0 +1,233 @@
+//! Map related types
+//!
+//! The map is saved as one chunk per tile field, each one
+//! with a value for every tile of the map.
+//!
+//! More about the tiles: https://github.com/OpenTTD/OpenTTD/blob/master/docs/landscape.html
+
+use crate::chunk_reader::{ChunkReader, ChunkDataReader};
+use crate::error::Error;
+
+/// Map informations
+#[derive(Debug, PartialEq)]
+pub struct Map {
+    /// Number of tiles on the X axis
+    pub width: u32,
+    /// Number of tiles on the Y axis
+    pub height: u32,
+    /// Tile type, bridges and tropic zone of each tile (MAPT)
+    pub tile_type: Vec<u8>,
+    /// Height of the northern corner of each tile (MAPH)
+    pub tile_height: Vec<u8>,
+    /// Field m1, mostly the owner (MAPO)
+    pub m1: Vec<u8>,
+    /// Field m2, mostly an index to a pool (MAP2)
+    pub m2: Vec<u16>,
+    /// Field m3 (M3LO)
+    pub m3: Vec<u8>,
+    /// Field m4 (M3HI)
+    pub m4: Vec<u8>,
+    /// Field m5 (MAP5)
+    pub m5: Vec<u8>,
+    /// Field m6 (MAPE)
+    pub m6: Vec<u8>,
+    /// Field m7 (MAP7)
+    pub m7: Vec<u8>,
+    /// Field m8 (MAP8), zeros on saves without it
+    pub m8: Vec<u16>,
+}
+
+/// All the fields of one tile
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct RawTile {
+    pub tile_type: u8,
+    pub height: u8,
+    pub m1: u8,
+    pub m2: u16,
+    pub m3: u8,
+    pub m4: u8,
+    pub m5: u8,
+    pub m6: u8,
+    pub m7: u8,
+    pub m8: u16,
+}
+
+impl Map {
+
+    /// Parse the map information
+    pub fn parse(buffer: &[u8]) -> Result<Map, Error> {
+
+        let mut chunk = ChunkReader::find(buffer, "MAPS")?; // 4d 41 50 53
+
+        // Fields from https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/saveload/map_sl.cpp
+
+        let width = chunk.fetch::<u32>()?; // _map_dim_x
+        let height = chunk.fetch::<u32>()?; // _map_dim_y
+
+        let tiles = width as usize * height as usize;
+
+        let m8 = match Self::parse_layer::<u16>(buffer, "MAP8", tiles) {
+            Err(Error::ChunkNotFound(_)) => vec![0; tiles],
+            m8 => m8?
+        };
+
+        Ok(Map {
+            width,
+            height,
+            tile_type: Self::parse_layer(buffer, "MAPT", tiles)?,
+            tile_height: Self::parse_layer(buffer, "MAPH", tiles)?,
+            m1: Self::parse_layer(buffer, "MAPO", tiles)?,
+            m2: Self::parse_layer(buffer, "MAP2", tiles)?,
+            m3: Self::parse_layer(buffer, "M3LO", tiles)?,
+            m4: Self::parse_layer(buffer, "M3HI", tiles)?,
+            m5: Self::parse_layer(buffer, "MAP5", tiles)?,
+            m6: Self::parse_layer(buffer, "MAPE", tiles)?,
+            m7: Self::parse_layer(buffer, "MAP7", tiles)?,
+            m8
+        })
+    }
+
+    /// Read the values of one tile field for the whole map
+    fn parse_layer<T>(buffer: &[u8], chunk_id: &str, tiles: usize) -> Result<Vec<T>, Error>
+        where T: ChunkDataReader<T>
+    {
+        let mut chunk = ChunkReader::find(buffer, chunk_id)?;
+
+        chunk.fetch_array::<T>(tiles)
+    }
+
+    /// Write the map back as the MAPS chunk and one
+    /// CH_RIFF chunk per tile field
+    ///
+    /// The MAP8 chunk is only written when it has data.
+    pub fn to_chunks(&self) -> Vec<u8> {
+
+        let mut buffer = vec![];
+
+        let mut dims = vec![];
+        dims.extend_from_slice(&self.width.to_be_bytes());
+        dims.extend_from_slice(&self.height.to_be_bytes());
+        write_riff(&mut buffer, "MAPS", &dims);
+
+        write_riff(&mut buffer, "MAPT", &self.tile_type);
+        write_riff(&mut buffer, "MAPH", &self.tile_height);
+        write_riff(&mut buffer, "MAPO", &self.m1);
+        write_riff(&mut buffer, "MAP2", &self.m2.iter().flat_map(|v| v.to_be_bytes()).collect::<Vec<u8>>());
+        write_riff(&mut buffer, "M3LO", &self.m3);
+        write_riff(&mut buffer, "M3HI", &self.m4);
+        write_riff(&mut buffer, "MAP5", &self.m5);
+        write_riff(&mut buffer, "MAPE", &self.m6);
+        write_riff(&mut buffer, "MAP7", &self.m7);
+
+        if self.m8.iter().any(|v| *v != 0) {
+            write_riff(&mut buffer, "MAP8", &self.m8.iter().flat_map(|v| v.to_be_bytes()).collect::<Vec<u8>>());
+        }
+
+        buffer
+    }
+
+    /// Return all the fields of the tile at x, y
+    pub fn tile(&self, x: u32, y: u32) -> Option<RawTile> {
+
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let i = (y * self.width + x) as usize;
+
+        Some(RawTile {
+            tile_type: self.tile_type[i],
+            height: self.tile_height[i],
+            m1: self.m1[i],
+            m2: self.m2[i],
+            m3: self.m3[i],
+            m4: self.m4[i],
+            m5: self.m5[i],
+            m6: self.m6[i],
+            m7: self.m7[i],
+            m8: self.m8[i],
+        })
+    }
+}
+
+/// Append a CH_RIFF chunk to the buffer
+fn write_riff(buffer: &mut Vec<u8>, chunk_id: &str, data: &[u8]) {
+    let size = data.len();
+
+    buffer.extend_from_slice(chunk_id.as_bytes());
+    // The upper bits of the chunk type extends the 24 bits size
+    buffer.push(((size >> 24) << 4) as u8);
+    buffer.extend_from_slice(&(size as u32).to_be_bytes()[1..]);
+    buffer.extend_from_slice(data);
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::map::*;
+
+    #[test]
+    fn base() -> Result<(), String> {
+
+        let map = Map::parse(&MAP)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(2, map.width);
+        assert_eq!(2, map.height);
+        assert_eq!(vec![0, 0, 0, 0], map.m8);
+
+        assert_eq!(Some(RawTile {
+            tile_type: 0x10,
+            height: 1,
+            m1: 0,
+            m2: 512,
+            m3: 0,
+            m4: 1,
+            m5: 10,
+            m6: 0,
+            m7: 0,
+            m8: 0
+        }), map.tile(1, 1));
+        assert_eq!(0x70, map.tile(0, 0).unwrap().tile_type);
+        assert_eq!(None, map.tile(2, 0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_chunks() -> Result<(), String> {
+
+        let map = Map::parse(&MAP)
+            .map_err(|e| e.to_string())?;
+
+        let chunks = map.to_chunks();
+        assert_eq!(MAP[..128], chunks[..]);
+        assert_eq!(map, Map::parse(&chunks).map_err(|e| e.to_string())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn truncated_layer() {
+
+        let mut buffer = MAP.to_vec();
+        buffer.truncate(26);
+
+        assert!(Map::parse(&buffer).is_err());
+    }
+
+    // 2x2 map, with the layers MAPT, MAPH, MAPO, MAP2, M3LO, M3HI, MAP5, MAPE and MAP7
+    static MAP: [u8; 132] = [
+        0x4d, 0x41, 0x50, 0x53, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02,
+        0x4d, 0x41, 0x50, 0x54, 0x00, 0x00, 0x00, 0x04, 0x70, 0x70, 0x60, 0x10,
+        0x4d, 0x41, 0x50, 0x48, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01,
+        0x4d, 0x41, 0x50, 0x4f, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x11, 0x00,
+        0x4d, 0x41, 0x50, 0x32, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x4d, 0x33, 0x4c, 0x4f, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00,
+        0x4d, 0x33, 0x48, 0x49, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01,
+        0x4d, 0x41, 0x50, 0x35, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x0a,
+        0x4d, 0x41, 0x50, 0x45, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00,
+        0x4d, 0x41, 0x50, 0x37, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00,
+        0x44, 0x41, 0x54, 0x45
+    ];
+}
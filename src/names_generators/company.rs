@@ -0,0 +1,45 @@
+//! Openttd company names generation API
+
+use crate::error::Error;
+use super::{NameGeneration, NameLanguage, TownName};
+
+pub struct CompanyName {}
+
+impl NameGeneration for CompanyName {
+    /// Generate the default company name, the town name with
+    /// the " Transport" suffix
+    fn generate(language: NameLanguage, seed: u32) -> Result<String, Error> {
+
+        let town = TownName::generate(language, seed)?;
+
+        Ok(format!("{} Transport", town))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::{CompanyName, NameGeneration};
+
+    #[test]
+    fn generate_petfield_transport() -> Result<(), String> {
+
+        let gen = CompanyName::generate(0, 2200570571)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!("Petfield Transport".to_string(), gen);
+
+        Ok(())
+    }
+
+    #[test]
+    fn generate_other_language() -> Result<(), String> {
+
+        let gen = CompanyName::generate(1, 0xdeadbeef)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!("Tours Transport".to_string(), gen);
+
+        Ok(())
+    }
+}
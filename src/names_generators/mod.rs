@@ -3,8 +3,11 @@
 use crate::error::Error;
 
 mod town;
+mod company;
 mod president;
 pub use town::TownName;
+pub(crate) use town::TOWNNAME_LANGUAGES;
+pub use company::CompanyName;
 pub use president::PresidentName;
 
 pub type NameLanguage = u16;
@@ -1,26 +1,51 @@
 //! Openttd town names generation API
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/townname.cpp
 
 use crate::error::Error;
 use crate::table::townname as table;
+use crate::table::townname::{CzechGender, CzechPattern};
 use super::{NameGeneration, NameLanguage};
 use crate::seeds;
 
 pub struct TownName {}
 
+/// Number of town name languages
+pub(crate) const TOWNNAME_LANGUAGES: NameLanguage = 21;
+
 impl NameGeneration for TownName {
     /// Generate the town name
     fn generate(language: NameLanguage, seed: u32) -> Result<String, Error> {
-
-        let generated = match language {
+        match language {
             0 => Ok(TownName::generate_english_original(seed)),
+            1 => Ok(TownName::generate_french(seed)),
+            2 => Ok(TownName::generate_german(seed)),
+            3 => Ok(TownName::generate_english_additional(seed)),
+            4 => Ok(TownName::generate_spanish(seed)),
+            5 => Ok(TownName::generate_silly(seed)),
+            6 => Ok(TownName::generate_swedish(seed)),
+            7 => Ok(TownName::generate_dutch(seed)),
+            8 => Ok(TownName::generate_finnish(seed)),
+            9 => Ok(TownName::generate_polish(seed)),
+            10 => Ok(TownName::generate_slovak(seed)),
+            11 => Ok(TownName::generate_norwegian(seed)),
+            12 => Ok(TownName::generate_hungarian(seed)),
+            13 => Ok(TownName::generate_austrian(seed)),
+            14 => Ok(TownName::generate_romanian(seed)),
+            15 => Ok(TownName::generate_czech(seed)),
+            16 => Ok(TownName::generate_swiss(seed)),
+            17 => Ok(TownName::generate_danish(seed)),
+            18 => Ok(TownName::generate_turkish(seed)),
+            19 => Ok(TownName::generate_italian(seed)),
+            20 => Ok(TownName::generate_catalan(seed)),
             _ => Err(Error::TypeNotSupportedYet("Towname language".to_string(), language))
-        }?;
-
-        Ok(format!("{} Transport", generated))
+        }
     }
 }
 
 impl TownName {
+
     fn generate_english_original(seed: u32) -> String {
 
         let mut name = String::new();
@@ -41,8 +66,597 @@ impl TownName {
             name.push_str(table::NAME_ORIGINAL_ENGLISH_6[fi]);
         }
 
+        // Ce, Ci => Ke, Ki
+        if name.starts_with("Ce") || name.starts_with("Ci") {
+            name.replace_range(0..1, "K");
+        }
+
+        replace_english_words(name, true)
+    }
+
+    fn generate_english_additional(seed: u32) -> String {
+
+        let mut name = String::new();
+
+        // First segment(optional)
+        if let Some(fi) = seeds::seed_chance_bias(0, table::NAME_ADDITIONAL_ENGLISH_PREFIX.len(), seed, 50) {
+            name.push_str(table::NAME_ADDITIONAL_ENGLISH_PREFIX[fi]);
+        }
+
+        // Middle segments
+        if seeds::seed_chance(3, 20, seed) >= 14 {
+            name.push_str(pick(table::NAME_ADDITIONAL_ENGLISH_1A, 6, seed));
+        } else {
+            name.push_str(pick(table::NAME_ADDITIONAL_ENGLISH_1B1, 6, seed));
+            name.push_str(pick(table::NAME_ADDITIONAL_ENGLISH_1B2, 9, seed));
+            if seeds::seed_chance(11, 20, seed) >= 4 {
+                name.push_str(pick(table::NAME_ADDITIONAL_ENGLISH_1B3A, 12, seed));
+            } else {
+                name.push_str(pick(table::NAME_ADDITIONAL_ENGLISH_1B3B, 12, seed));
+            }
+        }
+
+        name.push_str(pick(table::NAME_ADDITIONAL_ENGLISH_2, 14, seed));
+
+        // Last segment(optional)
+        if let Some(fi) = seeds::seed_chance_bias(15, table::NAME_ADDITIONAL_ENGLISH_3.len(), seed, 60) {
+            name.push_str(table::NAME_ADDITIONAL_ENGLISH_3[fi]);
+        }
+
+        replace_english_words(name, false)
+    }
+
+    fn generate_austrian(seed: u32) -> String {
+
+        let mut name = String::new();
+
+        // Bad, Maria, Gross, ...
+        if let Some(fi) = seeds::seed_chance_bias(0, table::NAME_AUSTRIAN_A1.len(), seed, 15) {
+            name.push_str(table::NAME_AUSTRIAN_A1[fi]);
+        }
+
+        let mut j = 0;
+        let i = seeds::seed_chance(4, 6, seed);
+        if i >= 4 {
+            // Kaisers-kirchen
+            name.push_str(pick(table::NAME_AUSTRIAN_A2, 7, seed));
+            name.push_str(pick(table::NAME_AUSTRIAN_A3, 13, seed));
+        } else if i >= 2 {
+            // St. Johann
+            name.push_str(pick(table::NAME_AUSTRIAN_A5, 7, seed));
+            name.push_str(pick(table::NAME_AUSTRIAN_A6, 9, seed));
+            // More likely to have a " an der " or " am "
+            j = 1;
+        } else {
+            // Zell
+            name.push_str(pick(table::NAME_AUSTRIAN_A4, 7, seed));
+        }
+
+        let i = seeds::seed_chance(1, 6, seed);
+        if i >= 4 - j {
+            // an der Donau (rivers)
+            name.push_str(pick(table::NAME_AUSTRIAN_F1, 4, seed));
+            name.push_str(pick(table::NAME_AUSTRIAN_F2, 5, seed));
+        } else if i >= 2 - j {
+            // am Dachstein (mountains)
+            name.push_str(pick(table::NAME_AUSTRIAN_B1, 4, seed));
+            name.push_str(pick(table::NAME_AUSTRIAN_B2, 5, seed));
+        }
+
+        name
+    }
+
+    fn generate_german(seed: u32) -> String {
+
+        let mut name = String::new();
+
+        let seed_derivative = seeds::seed_chance(7, 28, seed);
+
+        // Optional prefix
+        if seed_derivative == 12 || seed_derivative == 19 {
+            name.push_str(pick(table::NAME_GERMAN_PRE, 2, seed));
+        }
+
+        // Middle segments, or a real name
+        let i = seeds::seed_chance(3, table::NAME_GERMAN_REAL.len() + table::NAME_GERMAN_1.len(), seed);
+        if i < table::NAME_GERMAN_REAL.len() {
+            name.push_str(table::NAME_GERMAN_REAL[i]);
+        } else {
+            name.push_str(table::NAME_GERMAN_1[i - table::NAME_GERMAN_REAL.len()]);
+            name.push_str(pick(table::NAME_GERMAN_2, 5, seed));
+        }
+
+        // Optional suffix
+        if seed_derivative == 24 {
+            let i = seeds::seed_chance(9, table::NAME_GERMAN_4_AN_DER.len() + table::NAME_GERMAN_4_AM.len(), seed);
+            if i < table::NAME_GERMAN_4_AN_DER.len() {
+                name.push_str(table::NAME_GERMAN_3_AN_DER[0]);
+                name.push_str(table::NAME_GERMAN_4_AN_DER[i]);
+            } else {
+                name.push_str(table::NAME_GERMAN_3_AM[0]);
+                name.push_str(table::NAME_GERMAN_4_AM[i - table::NAME_GERMAN_4_AN_DER.len()]);
+            }
+        }
+
+        name
+    }
+
+    fn generate_spanish(seed: u32) -> String {
+        pick(table::NAME_SPANISH_REAL, 0, seed).to_string()
+    }
+
+    fn generate_french(seed: u32) -> String {
+        pick(table::NAME_FRENCH_REAL, 0, seed).to_string()
+    }
+
+    fn generate_silly(seed: u32) -> String {
+        format!("{}{}", pick(table::NAME_SILLY_1, 0, seed), pick(table::NAME_SILLY_2, 16, seed))
+    }
+
+    fn generate_swedish(seed: u32) -> String {
+
+        let mut name = String::new();
+
+        // First segment(optional)
+        if let Some(fi) = seeds::seed_chance_bias(0, table::NAME_SWEDISH_1.len(), seed, 50) {
+            name.push_str(table::NAME_SWEDISH_1[fi]);
+        }
+
+        // Middle segments
+        if seeds::seed_chance(4, 5, seed) >= 3 {
+            name.push_str(pick(table::NAME_SWEDISH_2, 7, seed));
+        } else {
+            name.push_str(pick(table::NAME_SWEDISH_2A, 7, seed));
+            name.push_str(pick(table::NAME_SWEDISH_2B, 10, seed));
+            name.push_str(pick(table::NAME_SWEDISH_2C, 13, seed));
+        }
+
+        name.push_str(pick(table::NAME_SWEDISH_3, 16, seed));
+
+        name
+    }
+
+    fn generate_dutch(seed: u32) -> String {
+
+        let mut name = String::new();
+
+        // First segment(optional)
+        if let Some(fi) = seeds::seed_chance_bias(0, table::NAME_DUTCH_1.len(), seed, 50) {
+            name.push_str(table::NAME_DUTCH_1[fi]);
+        }
+
+        // Middle segments
+        if seeds::seed_chance(6, 9, seed) > 4 {
+            name.push_str(pick(table::NAME_DUTCH_2, 9, seed));
+        } else {
+            name.push_str(pick(table::NAME_DUTCH_3, 9, seed));
+            name.push_str(pick(table::NAME_DUTCH_4, 12, seed));
+        }
+
+        name.push_str(pick(table::NAME_DUTCH_5, 15, seed));
+
+        name
+    }
+
+    fn generate_finnish(seed: u32) -> String {
+
+        // One or two parts
+        if seeds::seed_chance(0, 15, seed) >= 10 {
+            return pick(table::NAME_FINNISH_REAL, 2, seed).to_string();
+        }
+
+        if seeds::seed_chance(0, 15, seed) >= 5 {
+            // First part + "la"/"lä"
+            let mut name = pick(table::NAME_FINNISH_1, 0, seed).to_string();
+            if name.ends_with('i') {
+                name.pop();
+                name.push('e');
+            }
+
+            if name.contains(['a', 'o', 'u', 'A', 'O', 'U']) {
+                name.push_str("la");
+            } else {
+                name.push_str("lä");
+            }
+
+            return name;
+        }
+
+        // First part + second part
+        let mut name = String::new();
+
+        let sel = seeds::seed_chance(2, table::NAME_FINNISH_1.len() + table::NAME_FINNISH_2.len(), seed);
+        if sel >= table::NAME_FINNISH_1.len() {
+            name.push_str(table::NAME_FINNISH_2[sel - table::NAME_FINNISH_1.len()]);
+        } else {
+            name.push_str(table::NAME_FINNISH_1[sel]);
+        }
+
+        name.push_str(pick(table::NAME_FINNISH_3, 10, seed));
+
+        name
+    }
+
+    fn generate_polish(seed: u32) -> String {
+
+        let i = seeds::seed_chance(0,
+            table::NAME_POLISH_2_O.len() + table::NAME_POLISH_2_M.len()
+            + table::NAME_POLISH_2_F.len() + table::NAME_POLISH_2_N.len(),
+            seed);
+        let j = seeds::seed_chance(2, 20, seed);
+
+        // Real names
+        if i < table::NAME_POLISH_2_O.len() {
+            return pick(table::NAME_POLISH_2_O, 3, seed).to_string();
+        }
+
+        // Adjective, name and suffix of the same gender
+        let (prefixes, names, suffixes) = if i < table::NAME_POLISH_2_M.len() + table::NAME_POLISH_2_O.len() {
+            (table::NAME_POLISH_1_M, table::NAME_POLISH_2_M, table::NAME_POLISH_3_M)
+        } else if i < table::NAME_POLISH_2_F.len() + table::NAME_POLISH_2_M.len() + table::NAME_POLISH_2_O.len() {
+            (table::NAME_POLISH_1_F, table::NAME_POLISH_2_F, table::NAME_POLISH_3_F)
+        } else {
+            (table::NAME_POLISH_1_N, table::NAME_POLISH_2_N, table::NAME_POLISH_3_N)
+        };
+
+        let mut name = String::new();
+
+        if j < 4 {
+            name.push_str(pick(prefixes, 5, seed));
+        }
+
+        name.push_str(pick(names, 7, seed));
+
+        if (4..16).contains(&j) {
+            name.push_str(pick(suffixes, 10, seed));
+        }
+
+        name
+    }
+
+    fn generate_czech(seed: u32) -> String {
+
+        // 1:3 chance to use a real name
+        if seeds::seed_mod_chance(0, 4, seed) == 0 {
+            return table::NAME_CZECH_REAL[seeds::seed_mod_chance(4, table::NAME_CZECH_REAL.len(), seed)].to_string();
+        }
+
+        // Probability of prefixes/suffixes
+        // 0..11 prefix, 12..13 prefix+suffix, 14..17 suffix, 18..31 nothing
+        let prob_tails = seeds::seed_mod_chance(2, 32, seed);
+        let mut do_prefix = prob_tails < 12;
+        let do_suffix = prob_tails > 11 && prob_tails < 17;
+
+        let prefix = if do_prefix {
+            seeds::seed_mod_chance(5, table::NAME_CZECH_ADJ.len() * 12, seed) / 12
+        } else {
+            0
+        };
+        let suffix = if do_suffix {
+            seeds::seed_mod_chance(7, table::NAME_CZECH_SUFFIX.len(), seed)
+        } else {
+            0
+        };
+
+        // 3:1 chance to use a dynamic substantive
+        let mut stem = seeds::seed_mod_chance(9,
+            table::NAME_CZECH_SUBST_FULL.len() + 3 * table::NAME_CZECH_SUBST_STEM.len(),
+            seed);
+
+        let dynamic_subst = stem >= table::NAME_CZECH_SUBST_FULL.len();
+        let mut gender;
+        let mut choose;
+        let mut postfix = 0;
+        let mut ending = 0;
+
+        if !dynamic_subst {
+            gender = table::NAME_CZECH_SUBST_FULL[stem].gender;
+            choose = table::NAME_CZECH_SUBST_FULL[stem].choose;
+        } else {
+            stem = (stem - table::NAME_CZECH_SUBST_FULL.len()) % table::NAME_CZECH_SUBST_STEM.len();
+            gender = table::NAME_CZECH_SUBST_STEM[stem].gender;
+            choose = table::NAME_CZECH_SUBST_STEM[stem].choose;
+            let allow = table::NAME_CZECH_SUBST_STEM[stem].allow;
+
+            // 1:1 chance that a postfix will be inserted
+            postfix = seeds::seed_mod_chance(14, table::NAME_CZECH_SUBST_POSTFIX.len() * 2, seed);
+            if choose & table::CZC_POSTFIX != 0 {
+                postfix %= table::NAME_CZECH_SUBST_POSTFIX.len();
+            }
+            if choose & table::CZC_NOPOSTFIX != 0 {
+                postfix += table::NAME_CZECH_SUBST_POSTFIX.len();
+            }
+            if postfix < table::NAME_CZECH_SUBST_POSTFIX.len() {
+                choose |= table::CZC_POSTFIX;
+            } else {
+                choose |= table::CZC_NOPOSTFIX;
+            }
+
+            // The endings of a good gender are grouped together
+            let matches = |e: &table::CzechNameSubst| {
+                gender == CzechGender::Free
+                    || (gender == CzechGender::NFree && e.gender != CzechGender::SNeut && e.gender != CzechGender::PNeut)
+                    || gender == e.gender
+            };
+            let endings = table::NAME_CZECH_SUBST_ENDING;
+            let start = endings.iter().position(matches).unwrap_or(0);
+            let stop = endings[start..].iter().position(|e| !matches(e)).map_or(endings.len(), |p| start + p);
+
+            let candidates = (start..stop)
+                .filter(|i| endings[*i].choose & choose == choose && endings[*i].allow & allow != 0)
+                .collect::<Vec<usize>>();
+
+            if !candidates.is_empty() {
+                ending = candidates[seeds::seed_mod_chance(16, candidates.len(), seed)];
+            }
+
+            // Always a real gender, from the ending
+            gender = endings[ending].gender;
+        }
+
+        if do_prefix && table::NAME_CZECH_ADJ[prefix].choose & choose != choose {
+            // Throw away non-matching prefix
+            do_prefix = false;
+        }
+
+        let mut name = String::new();
+
+        if do_prefix {
+            let adj = &table::NAME_CZECH_ADJ[prefix];
+            let last = adj.name.char_indices().last().map_or(0, |(i, _)| i);
+
+            if gender == CzechGender::SMasc && adj.pattern == CzechPattern::Privl {
+                // -ovX -> -uv
+                name.push_str(&adj.name[..last.saturating_sub(2)]);
+                name.push_str("uv");
+            } else {
+                name.push_str(&adj.name[..last]);
+                name.push_str(table::NAME_CZECH_PATMOD[gender as usize][adj.pattern as usize]);
+            }
+
+            name.push(' ');
+        }
+
+        if dynamic_subst {
+            name.push_str(table::NAME_CZECH_SUBST_STEM[stem].name);
+
+            if postfix < table::NAME_CZECH_SUBST_POSTFIX.len() {
+                let poststr = table::NAME_CZECH_SUBST_POSTFIX[postfix].as_bytes();
+                let endstr = table::NAME_CZECH_SUBST_ENDING[ending].name.as_bytes();
+                let at = |s: &[u8], i: usize| s.get(i).copied().unwrap_or(0);
+
+                // Kill the "avava" and "Jananna"-like cases
+                if poststr.len() < 2 || poststr.len() > endstr.len()
+                    || ((at(poststr, 1) != b'v' || at(poststr, 1) != at(endstr, 1)) && at(poststr, 2) != at(endstr, 1)) {
+
+                    name.push_str(table::NAME_CZECH_SUBST_POSTFIX[postfix]);
+
+                    // k-i -> c-i, h-i -> z-i
+                    if at(endstr, 0) == b'i' {
+                        match name.pop() {
+                            Some('k') => name.push('c'),
+                            Some('h') => name.push('z'),
+                            Some(c) => name.push(c),
+                            None => {}
+                        }
+                    }
+                }
+            }
+
+            name.push_str(table::NAME_CZECH_SUBST_ENDING[ending].name);
+        } else {
+            name.push_str(table::NAME_CZECH_SUBST_FULL[stem].name);
+        }
+
+        if do_suffix {
+            name.push(' ');
+            name.push_str(table::NAME_CZECH_SUFFIX[suffix]);
+        }
+
+        name
+    }
+
+    fn generate_romanian(seed: u32) -> String {
+        pick(table::NAME_ROMANIAN_REAL, 0, seed).to_string()
+    }
+
+    fn generate_slovak(seed: u32) -> String {
+        pick(table::NAME_SLOVAK_REAL, 0, seed).to_string()
+    }
+
+    fn generate_norwegian(seed: u32) -> String {
+
+        // 3/16 chance of a real name
+        if seeds::seed_chance(0, 15, seed) < 3 {
+            return pick(table::NAME_NORWEGIAN_REAL, 4, seed).to_string();
+        }
+
+        format!("{}{}", pick(table::NAME_NORWEGIAN_1, 4, seed), pick(table::NAME_NORWEGIAN_2, 11, seed))
+    }
+
+    fn generate_hungarian(seed: u32) -> String {
+
+        if seeds::seed_chance(12, 15, seed) < 3 {
+            return pick(table::NAME_HUNGARIAN_REAL, 0, seed).to_string();
+        }
+
+        let mut name = String::new();
+
+        // First segment(optional)
+        let i = seeds::seed_chance(3, table::NAME_HUNGARIAN_1.len() * 3, seed);
+        if i < table::NAME_HUNGARIAN_1.len() {
+            name.push_str(table::NAME_HUNGARIAN_1[i]);
+        }
+
+        // Middle segments
+        name.push_str(pick(table::NAME_HUNGARIAN_2, 3, seed));
+        name.push_str(pick(table::NAME_HUNGARIAN_3, 6, seed));
+
+        // Last segment(optional)
+        let i = seeds::seed_chance(10, table::NAME_HUNGARIAN_4.len() * 3, seed);
+        if i < table::NAME_HUNGARIAN_4.len() {
+            name.push_str(table::NAME_HUNGARIAN_4[i]);
+        }
+
+        name
+    }
+
+    fn generate_swiss(seed: u32) -> String {
+        pick(table::NAME_SWISS_REAL, 0, seed).to_string()
+    }
+
+    fn generate_danish(seed: u32) -> String {
+
+        let mut name = String::new();
+
+        // First segment(optional)
+        if let Some(fi) = seeds::seed_chance_bias(0, table::NAME_DANISH_1.len(), seed, 50) {
+            name.push_str(table::NAME_DANISH_1[fi]);
+        }
+
+        name.push_str(pick(table::NAME_DANISH_2, 7, seed));
+        name.push_str(pick(table::NAME_DANISH_3, 16, seed));
+
         name
     }
+
+    fn generate_turkish(seed: u32) -> String {
+
+        let mut name = String::new();
+
+        match seeds::seed_mod_chance(0, 5, seed) {
+            0 => {
+                name.push_str(pick_mod(table::NAME_TURKISH_PREFIX, 2, seed));
+                name.push_str(pick_mod(table::NAME_TURKISH_MIDDLE, 4, seed));
+
+                // Optional suffix
+                if seeds::seed_mod_chance(0, 7, seed) == 0 {
+                    name.push_str(pick_mod(table::NAME_TURKISH_SUFFIX, 10, seed));
+                }
+            },
+            1 | 2 => {
+                name.push_str(pick_mod(table::NAME_TURKISH_PREFIX, 2, seed));
+                name.push_str(pick_mod(table::NAME_TURKISH_SUFFIX, 4, seed));
+            },
+            _ => {
+                name.push_str(pick_mod(table::NAME_TURKISH_REAL, 4, seed));
+            }
+        }
+
+        name
+    }
+
+    fn generate_italian(seed: u32) -> String {
+
+        // Real names
+        if seeds::seed_mod_chance(0, 6, seed) == 0 {
+            return pick_mod(table::NAME_ITALIAN_REAL, 4, seed).to_string();
+        }
+
+        let mut name = String::new();
+
+        if seeds::seed_mod_chance(0, 8, seed) == 0 {
+            name.push_str(pick_mod(table::NAME_ITALIAN_PREF, 11, seed));
+        }
+
+        // Masculine or feminine form
+        let i = seeds::seed_chance(0, 2, seed);
+        if i == 0 {
+            name.push_str(pick_mod(table::NAME_ITALIAN_1M, 4, seed));
+        } else {
+            name.push_str(pick_mod(table::NAME_ITALIAN_1F, 4, seed));
+        }
+
+        if seeds::seed_mod_chance(3, 3, seed) == 0 {
+            name.push_str(pick_mod(table::NAME_ITALIAN_2, 11, seed));
+            name.push_str(["o", "a"][i]);
+        } else {
+            name.push_str(pick_mod(table::NAME_ITALIAN_2I, 16, seed));
+        }
+
+        if seeds::seed_mod_chance(15, 4, seed) == 0 {
+            if seeds::seed_mod_chance(5, 2, seed) == 0 {
+                // Generic suffix
+                name.push_str(pick_mod(table::NAME_ITALIAN_3, 4, seed));
+            } else {
+                // River name suffix
+                name.push_str(pick_mod(table::NAME_ITALIAN_RIVER1, 4, seed));
+                name.push_str(pick_mod(table::NAME_ITALIAN_RIVER2, 16, seed));
+            }
+        }
+
+        name
+    }
+
+    fn generate_catalan(seed: u32) -> String {
+
+        // Real names
+        if seeds::seed_mod_chance(0, 3, seed) == 0 {
+            return pick_mod(table::NAME_CATALAN_REAL, 4, seed).to_string();
+        }
+
+        let mut name = String::new();
+
+        if seeds::seed_mod_chance(0, 2, seed) == 0 {
+            name.push_str(pick_mod(table::NAME_CATALAN_PREF, 11, seed));
+        }
+
+        // Masculine or feminine form
+        if seeds::seed_chance(0, 2, seed) == 0 {
+            name.push_str(pick_mod(table::NAME_CATALAN_1M, 4, seed));
+            name.push_str(pick_mod(table::NAME_CATALAN_2M, 11, seed));
+        } else {
+            name.push_str(pick_mod(table::NAME_CATALAN_1F, 4, seed));
+            name.push_str(pick_mod(table::NAME_CATALAN_2F, 11, seed));
+        }
+
+        if seeds::seed_mod_chance(15, 5, seed) == 0 {
+            if seeds::seed_mod_chance(5, 2, seed) == 0 {
+                // Generic suffix
+                name.push_str(pick_mod(table::NAME_CATALAN_3, 4, seed));
+            } else {
+                // River name suffix
+                name.push_str(pick_mod(table::NAME_CATALAN_RIVER1, 4, seed));
+            }
+        }
+
+        name
+    }
+}
+
+/// Pick one of the parts, by the seed
+fn pick(parts: &'static [&'static str], shift_by: u8, seed: u32) -> &'static str {
+    parts[seeds::seed_chance(shift_by, parts.len(), seed)]
+}
+
+/// Pick one of the parts, by the modulo of the seed
+fn pick_mod(parts: &'static [&'static str], shift_by: u8, seed: u32) -> &'static str {
+    parts[seeds::seed_mod_chance(shift_by, parts.len(), seed)]
+}
+
+/// Replace the first four letters of the bad names
+fn replace_english_words(mut name: String, original: bool) -> String {
+
+    let replacements = [
+        ("Cunt", "East"),
+        ("Slag", "Pits"),
+        ("Slut", "Edin"),
+        ("Fart", if original { "Fart" } else { "Boot" }),
+        ("Drar", "Quar"),
+        ("Dreh", "Bash"),
+        ("Frar", "Shor"),
+        ("Grar", "Aber"),
+        ("Brar", "Over"),
+        ("Wrar", if original { "Inve" } else { "Stan" }),
+    ];
+
+    for (org, rep) in replacements {
+        if name.starts_with(org) {
+            name.replace_range(0..4, rep);
+        }
+    }
+
+    name
 }
 
 #[cfg(test)]
@@ -56,7 +670,77 @@ mod test {
         let gen = TownName::generate(0, 2200570571)
             .map_err(|e| e.to_string())?;
 
-        assert_eq!("Petfield Transport".to_string(), gen);
+        assert_eq!("Petfield".to_string(), gen);
+
+        Ok(())
+    }
+
+    #[test]
+    fn generate_english_replaced_words() -> Result<(), String> {
+
+        // Wrar => Inve
+        let gen = TownName::generate(0, 7)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!("Invenville".to_string(), gen);
+
+        // Fart => Boot, only on the additional names
+        let gen = TownName::generate(3, 705900581)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!("Boothaven".to_string(), gen);
+
+        Ok(())
+    }
+
+    #[test]
+    fn generate_languages() -> Result<(), String> {
+
+        let expected = [
+            (1, "Tours", "Rochefort"),
+            (2, "Oldenfurt", "Ilsenheim"),
+            (3, "Grimpool", "Little Sustock Heath"),
+            (4, "La Quiaca", "Barranca"),
+            (5, "Jellygate", "Gigglegate"),
+            (6, "Kungudde", "Tringviken"),
+            (7, "Enkoever", "Zuid Uhage"),
+            (8, "Salo", "Lieksa"),
+            (9, "Ruda Dolna", "Zbąszynko"),
+            (10, "Snina", "Vrable"),
+            (11, "Sørsvik", "Flatrud"),
+            (12, "Püspökmérges", "Fehérszalontaúr"),
+            (13, "Bad Heiligenhaag ob der Gail", "Markt Taferl an der Ybbs"),
+            (14, "Sfântu Gheorghe", "Turda"),
+            (15, "Vodka", "Hradovo"),
+            (16, "Schaffhausen", "Winterthur"),
+            (17, "Hadved", "Vester Ulstervig"),
+            (18, "Osmaniye", "Doğuhüyük"),
+            (19, "Gallaminore", "Roccaforte"),
+            (20, "Vallgran", "Pedrapetita"),
+        ];
+
+        for (language, first, second) in expected {
+            let gen = TownName::generate(language, 0xdeadbeef)
+                .map_err(|e| e.to_string())?;
+            assert_eq!(first.to_string(), gen);
+
+            let gen = TownName::generate(language, 0xecf3eadb)
+                .map_err(|e| e.to_string())?;
+            assert_eq!(second.to_string(), gen);
+        }
+
+        assert!(TownName::generate(21, 0xdeadbeef).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn generate_czech_prefix() -> Result<(), String> {
+
+        let gen = TownName::generate(15, 7)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!("Horní Sedlec".to_string(), gen);
 
         Ok(())
     }
@@ -0,0 +1,458 @@
+//! Order related types
+//!
+//! The orders are saved on a pool, each order list only saves
+//! his first order, and the orders are linked by the next
+//! references. The vehicles save the index of their list.
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/saveload/order_sl.cpp
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/order_base.h
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/order_type.h
+
+use crate::bitmath::{gb, has_bit};
+use crate::chunk_reader::ChunkReader;
+use crate::error::Error;
+use crate::vehicle::Vehicles;
+
+/// No speed limit on the order
+const UNLIMITED_SPEED: u16 = 0xFFFF;
+
+/// Flags of the timetable, saved on the order flags
+const WAIT_TIMETABLED_BIT: u8 = 3;
+const TRAVEL_TIMETABLED_BIT: u8 = 7;
+
+/// Depot orders, saved on the order flags
+const ODTFB_SERVICE: u8 = 0x01;
+const ODATFB_HALT: u8 = 0x01;
+const ODATFB_NEAREST_DEPOT: u8 = 0x02;
+
+/// Type of an order, saved on the bits 0..3 of the type
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum OrderType {
+    Nothing,
+    GoToStation,
+    GoToDepot,
+    /// Current order of a vehicle loading at a station
+    Loading,
+    /// Current order of a vehicle leaving a station
+    LeaveStation,
+    /// Invalid order, like a station removed
+    Dummy,
+    GoToWaypoint,
+    /// Jump to another order, on a condition
+    Conditional,
+    /// Station visited without order, added by the game
+    Implicit,
+}
+
+impl TryFrom<u8> for OrderType {
+    type Error = Error;
+
+    fn try_from(order_type: u8) -> Result<Self, Self::Error> {
+        match order_type {
+            0 => Ok(OrderType::Nothing),
+            1 => Ok(OrderType::GoToStation),
+            2 => Ok(OrderType::GoToDepot),
+            3 => Ok(OrderType::Loading),
+            4 => Ok(OrderType::LeaveStation),
+            5 => Ok(OrderType::Dummy),
+            6 => Ok(OrderType::GoToWaypoint),
+            7 => Ok(OrderType::Conditional),
+            8 => Ok(OrderType::Implicit),
+            t => Err(Error::DataCorruption(format!("Unknown order type: {}", t)))
+        }
+    }
+}
+
+/// Stations where the vehicle stops, saved on the bits 6..7 of the type
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum OrderNonStop {
+    StopEverywhere,
+    NoStopAtIntermediateStations,
+    NoStopAtDestinationStation,
+    NoStopAtAnyStation,
+}
+
+impl From<u8> for OrderNonStop {
+    fn from(non_stop: u8) -> Self {
+        match non_stop & 0x03 {
+            0 => OrderNonStop::StopEverywhere,
+            1 => OrderNonStop::NoStopAtIntermediateStations,
+            2 => OrderNonStop::NoStopAtDestinationStation,
+            _ => OrderNonStop::NoStopAtAnyStation
+        }
+    }
+}
+
+/// Loading at the station, saved on the bits 4..6 of the flags
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum OrderLoad {
+    IfPossible,
+    /// Full load of all the cargos
+    FullLoad,
+    /// Full load of any cargo
+    FullLoadAny,
+    NoLoad,
+}
+
+impl TryFrom<u8> for OrderLoad {
+    type Error = Error;
+
+    fn try_from(load: u8) -> Result<Self, Self::Error> {
+        match load {
+            0 => Ok(OrderLoad::IfPossible),
+            2 => Ok(OrderLoad::FullLoad),
+            3 => Ok(OrderLoad::FullLoadAny),
+            4 => Ok(OrderLoad::NoLoad),
+            l => Err(Error::DataCorruption(format!("Unknown order load type: {}", l)))
+        }
+    }
+}
+
+/// Unloading at the station, saved on the bits 0..2 of the flags
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum OrderUnload {
+    IfPossible,
+    /// Unload all the cargo, even the one not accepted
+    Unload,
+    /// Transfer the cargo to the next vehicles
+    Transfer,
+    NoUnload,
+}
+
+impl TryFrom<u8> for OrderUnload {
+    type Error = Error;
+
+    fn try_from(unload: u8) -> Result<Self, Self::Error> {
+        match unload {
+            0 => Ok(OrderUnload::IfPossible),
+            1 => Ok(OrderUnload::Unload),
+            2 => Ok(OrderUnload::Transfer),
+            4 => Ok(OrderUnload::NoUnload),
+            u => Err(Error::DataCorruption(format!("Unknown order unload type: {}", u)))
+        }
+    }
+}
+
+/// Order of a vehicle
+#[derive(Debug, PartialEq, Clone)]
+pub struct Order {
+    /// Index of the order on the pool
+    pub index: usize,
+    /// Type of the order
+    pub order_type: OrderType,
+    /// Stations where the vehicle stops on the way
+    pub non_stop: OrderNonStop,
+    /// Station, waypoint or depot index, or the condition
+    /// of the conditional orders
+    pub destination: u16,
+    /// Flags of the order, their meaning depends on the type
+    pub flags: u8,
+    /// Next order of the list
+    pub next: Option<usize>,
+    /// Waiting time at the destination, in ticks
+    pub wait_time: u16,
+    /// Travel time to the destination, in ticks
+    pub travel_time: u16,
+    /// Max speed to the destination
+    pub max_speed: Option<u16>,
+}
+
+impl Order {
+
+    /// Parse all the orders of the pool
+    pub fn parse_list(buffer: &[u8]) -> Result<Vec<Order>, Error> {
+
+        let mut chunk = ChunkReader::find(buffer, "ORDR")?; // 4f 52 44 52
+
+        let mut orders = vec![];
+
+        loop {
+            // Empty slices are free indexes of the pool
+            if chunk.gamma > 0 {
+                orders.push(Order::parse(&mut chunk)?);
+            }
+
+            match chunk.advance_slice()? {
+                Some(c) => chunk = c,
+                None => break
+            }
+        }
+
+        Ok(orders)
+    }
+
+    /// Parse the order information
+    pub fn parse(chunk: &mut ChunkReader) -> Result<Order, Error> {
+
+        let index = chunk.slice_idx;
+        let raw_type = chunk.fetch::<u8>()?; // type
+        let flags = chunk.fetch::<u8>()?; // flags
+        let destination = chunk.fetch::<u16>()?; // dest
+        // The references are saved as the index plus one
+        let next = (chunk.fetch::<u32>()? as usize).checked_sub(1); // next
+        chunk.advance::<u8>()?; // refit_cargo
+        let wait_time = chunk.fetch::<u16>()?; // wait_time
+        let travel_time = chunk.fetch::<u16>()?; // travel_time
+        let max_speed = match chunk.fetch::<u16>()? { // max_speed
+            UNLIMITED_SPEED => None,
+            speed => Some(speed)
+        };
+
+        let order_type = OrderType::try_from(gb(raw_type as u32, 0, 4) as u8)?;
+
+        Ok(Order {
+            index,
+            order_type,
+            non_stop: OrderNonStop::from(gb(raw_type as u32, 6, 2) as u8),
+            destination,
+            flags,
+            next,
+            wait_time,
+            travel_time,
+            max_speed
+        })
+    }
+
+    /// Station of the order, with the waypoints and the implicit orders
+    pub fn station(&self) -> Option<usize> {
+        match self.order_type {
+            OrderType::GoToStation | OrderType::GoToWaypoint | OrderType::Implicit => Some(self.destination as usize),
+            _ => None
+        }
+    }
+
+    /// Loading at the station, only on the station orders
+    pub fn load(&self) -> Option<OrderLoad> {
+        (self.order_type == OrderType::GoToStation)
+            .then(|| OrderLoad::try_from(gb(self.flags as u32, 4, 3) as u8).ok())
+            .flatten()
+    }
+
+    /// Unloading at the station, only on the station orders
+    pub fn unload(&self) -> Option<OrderUnload> {
+        (self.order_type == OrderType::GoToStation)
+            .then(|| OrderUnload::try_from(gb(self.flags as u32, 0, 3) as u8).ok())
+            .flatten()
+    }
+
+    /// Check if the vehicle only goes to the depot for a service
+    pub fn is_depot_service_only(&self) -> bool {
+        self.order_type == OrderType::GoToDepot && self.flags & ODTFB_SERVICE != 0
+    }
+
+    /// Check if the vehicle stops in the depot
+    pub fn is_depot_halt(&self) -> bool {
+        self.order_type == OrderType::GoToDepot && gb(self.flags as u32, 4, 3) as u8 & ODATFB_HALT != 0
+    }
+
+    /// Check if the vehicle goes to the nearest depot, without destination
+    pub fn is_nearest_depot(&self) -> bool {
+        self.order_type == OrderType::GoToDepot && gb(self.flags as u32, 4, 3) as u8 & ODATFB_NEAREST_DEPOT != 0
+    }
+
+    /// Order to jump to, on the conditional orders
+    pub fn condition_skip_to(&self) -> Option<u8> {
+        (self.order_type == OrderType::Conditional).then_some(self.flags)
+    }
+
+    /// Check if the waiting time is set by the player
+    ///
+    /// The other waiting times are the last measured ones.
+    pub fn is_wait_timetabled(&self) -> bool {
+        match self.order_type {
+            OrderType::Implicit => false,
+            OrderType::Conditional => self.wait_time > 0,
+            _ => has_bit(self.flags as u32, WAIT_TIMETABLED_BIT)
+        }
+    }
+
+    /// Check if the travel time is set by the player
+    ///
+    /// The other travel times are the last measured ones.
+    pub fn is_travel_timetabled(&self) -> bool {
+        match self.order_type {
+            OrderType::Conditional => self.travel_time > 0,
+            _ => has_bit(self.flags as u32, TRAVEL_TIMETABLED_BIT)
+        }
+    }
+}
+
+/// Orders of a vehicle, maybe shared by several ones
+#[derive(Debug, PartialEq)]
+pub struct OrderList {
+    /// Index of the list on the pool
+    pub index: usize,
+    /// Orders in the order of the list
+    pub orders: Vec<Order>,
+    /// Indexes of the vehicles sharing the list
+    pub vehicles: Vec<usize>,
+}
+
+impl OrderList {
+
+    /// Parse all the order lists, with their orders and vehicles
+    pub fn parse_list(buffer: &[u8], vehicles: &Vehicles) -> Result<Vec<OrderList>, Error> {
+
+        let orders = Order::parse_list(buffer)?;
+
+        let mut chunk = ChunkReader::find(buffer, "ORDL")?; // 4f 52 44 4c
+
+        let mut lists = vec![];
+
+        loop {
+            // Empty slices are free indexes of the pool
+            if chunk.gamma > 0 {
+                lists.push(OrderList::parse(&mut chunk, &orders)?);
+            }
+
+            match chunk.advance_slice()? {
+                Some(c) => chunk = c,
+                None => break
+            }
+        }
+
+        for (_, vehicle) in vehicles.iter() {
+            if let Some(list) = vehicle.order_list.and_then(|l| lists.iter_mut().find(|o| o.index == l)) {
+                list.vehicles.push(vehicle.index);
+            }
+        }
+        for list in lists.iter_mut() {
+            list.vehicles.sort_unstable();
+        }
+
+        Ok(lists)
+    }
+
+    /// Parse the order list, without the vehicles
+    ///
+    /// The orders must be sorted by the index, like they are parsed.
+    pub fn parse(chunk: &mut ChunkReader, orders: &[Order]) -> Result<OrderList, Error> {
+
+        let index = chunk.slice_idx;
+        let mut next = (chunk.fetch::<u32>()? as usize).checked_sub(1); // first
+
+        let mut list = vec![];
+        while let Some(order_index) = next {
+            // A loop on the next references would never end
+            if list.len() >= orders.len() {
+                return Err(Error::DataCorruption(format!("Order list {} with a loop on his orders", index)));
+            }
+
+            let order = orders.binary_search_by_key(&order_index, |o| o.index)
+                .map(|i| &orders[i])
+                .map_err(|_| Error::DataCorruption(format!("Order list {} with the unknown order {}", index, order_index)))?;
+
+            list.push(order.clone());
+            next = order.next;
+        }
+
+        Ok(OrderList {
+            index,
+            orders: list,
+            vehicles: vec![]
+        })
+    }
+
+    /// Check if the list is shared by several vehicles
+    pub fn is_shared(&self) -> bool {
+        self.vehicles.len() > 1
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+
+    use crate::order::*;
+
+    #[test]
+    fn base() -> Result<(), String> {
+
+        let orders = Order::parse_list(&ORDR)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(10, orders.len());
+
+        let order = &orders[0];
+        assert_eq!(OrderType::GoToStation, order.order_type);
+        assert_eq!(OrderNonStop::NoStopAtIntermediateStations, order.non_stop);
+        assert_eq!(Some(0), order.station());
+        assert_eq!(Some(OrderLoad::IfPossible), order.load());
+        assert_eq!(Some(OrderUnload::IfPossible), order.unload());
+        assert_eq!(Some(1), order.next);
+        assert_eq!(666, order.wait_time);
+        assert_eq!(2812, order.travel_time);
+        assert!(!order.is_wait_timetabled());
+        assert!(!order.is_travel_timetabled());
+        assert_eq!(None, order.max_speed);
+
+        let transfer = &orders[4];
+        assert_eq!(OrderNonStop::StopEverywhere, transfer.non_stop);
+        assert_eq!(Some(OrderLoad::FullLoadAny), transfer.load());
+        assert_eq!(Some(OrderUnload::Transfer), transfer.unload());
+
+        let implicit = &orders[9];
+        assert_eq!(OrderType::Implicit, implicit.order_type);
+        assert_eq!(Some(11), implicit.station());
+        assert_eq!(None, implicit.load());
+
+        let mut buffer = ORDR.to_vec();
+        buffer[6] = 0x0f;
+        assert!(Order::parse_list(&buffer).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn lists() -> Result<(), String> {
+
+        let orders = Order::parse_list(&ORDR)
+            .map_err(|e| e.to_string())?;
+
+        let mut chunk = ChunkReader::find(&ORDL, "ORDL")
+            .map_err(|e| e.to_string())?;
+        let list = OrderList::parse(&mut chunk, &orders)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(0, list.index);
+        assert_eq!(vec![0, 1, 2, 3, 6], list.orders.iter().map(|o| o.index).collect::<Vec<usize>>());
+        assert!(!list.is_shared());
+
+        let mut chunk = chunk.advance_slice()
+            .map_err(|e| e.to_string())?
+            .ok_or("Missing list")?;
+        let list = OrderList::parse(&mut chunk, &orders)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(1, list.index);
+        assert_eq!(vec![4, 9, 5, 8], list.orders.iter().map(|o| o.index).collect::<Vec<usize>>());
+        assert_eq!(vec![Some(0), Some(11), Some(12), Some(10)], list.orders.iter().map(|o| o.station()).collect::<Vec<Option<usize>>>());
+
+        // Without the orders, the references are unknown
+        let mut chunk = ChunkReader::find(&ORDL, "ORDL")
+            .map_err(|e| e.to_string())?;
+        assert!(OrderList::parse(&mut chunk, &orders[..3]).is_err());
+
+        Ok(())
+    }
+
+    /// The first ten orders of the example save
+    pub(crate) static ORDR: [u8; 166] = [
+        0x4f, 0x52, 0x44, 0x52, 0x01, 0x10, 0x61, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xfe, 0x02,
+        0x9a, 0x0a, 0xfc, 0xff, 0xff, 0x10, 0x21, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0xfe, 0x02,
+        0x9a, 0x02, 0x9a, 0xff, 0xff, 0x10, 0x61, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x04, 0xfe, 0x02,
+        0x9a, 0x08, 0xac, 0xff, 0xff, 0x10, 0x21, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x07, 0xfe, 0x01,
+        0x72, 0x04, 0xea, 0xff, 0xff, 0x10, 0x21, 0x32, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a, 0xfe, 0x01,
+        0x28, 0x02, 0x9a, 0xff, 0xff, 0x10, 0x21, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x09, 0xfe, 0x01,
+        0x28, 0x03, 0xc2, 0xff, 0xff, 0x10, 0x61, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0xfe, 0x01,
+        0x72, 0x05, 0x34, 0xff, 0xff, 0x10, 0x21, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x12, 0xfe, 0x02,
+        0xe4, 0x07, 0xce, 0xff, 0xff, 0x10, 0x21, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x00, 0xfe, 0x01,
+        0x28, 0x01, 0xbc, 0xff, 0xff, 0x10, 0x08, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x06, 0xfe, 0x00,
+        0x00, 0x00, 0x00, 0xff, 0xff, 0x00,
+    ];
+
+    /// The first two order lists of the example save
+    pub(crate) static ORDL: [u8; 16] = [
+        0x4f, 0x52, 0x44, 0x4c, 0x01, 0x05, 0x00, 0x00, 0x00, 0x01, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+    ];
+}
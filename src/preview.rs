@@ -0,0 +1,269 @@
+//! Terminal preview of the map
+//!
+//! Downsample the map tiles to a grid of cells, that can be
+//! printed with ANSI colours. Each character shows two cells,
+//! using the upper half block with the foreground and background
+//! colours.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::bitmath::gb;
+use crate::error::Error;
+use crate::map::Map;
+use crate::tile::{Tile, Owner};
+
+/// Area of the map, in tiles
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Region {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Region {
+
+    /// Region with all the map
+    pub fn whole(map: &Map) -> Region {
+        Region {
+            x: 0,
+            y: 0,
+            width: map.width,
+            height: map.height
+        }
+    }
+}
+
+impl FromStr for Region {
+    type Err = Error;
+
+    /// Parse the region from the `x,y,width,height` format
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+
+        let values = s.split(',')
+            .map(|v| v.trim().parse::<u32>())
+            .collect::<Result<Vec<u32>, _>>()
+            .map_err(|e| Error::InvalidArgument(format!("Region {}: {}", s, e)))?;
+
+        match values[..] {
+            [x, y, width, height] => Ok(Region { x, y, width, height }),
+            _ => Err(Error::InvalidArgument(format!("Region {}: expected x,y,width,height", s)))
+        }
+    }
+}
+
+/// What a preview cell shows
+///
+/// When a cell covers many tiles, the kind with the highest priority
+/// wins, the priority is the order of the variants: the companies
+/// before the rails, the roads, the towns, the water and the land.
+/// A cell on the coast shows the water.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum PreviewCell {
+    Void,
+    Land,
+    Water,
+    Town,
+    Road,
+    Rail,
+    /// Stations and buildings of a company
+    Company(u8),
+}
+
+impl PreviewCell {
+
+    /// Kind of the tile on the preview
+    fn from_tile(tile: &Tile) -> PreviewCell {
+        match tile {
+            Tile::Void(_) => PreviewCell::Void,
+            Tile::Water(_) => PreviewCell::Water,
+            Tile::House(_) => PreviewCell::Town,
+            Tile::Railway(_) => PreviewCell::Rail,
+            Tile::Road(_) if tile.track_bits().is_some() => PreviewCell::Rail,
+            Tile::Road(_) => PreviewCell::Road,
+            Tile::TunnelBridge(raw) => match gb(raw.m5 as u32, 2, 2) {
+                0 => PreviewCell::Rail, // TRANSPORT_RAIL
+                1 => PreviewCell::Road, // TRANSPORT_ROAD
+                _ => PreviewCell::Water
+            },
+            Tile::Station(_) | Tile::Object(_) => match tile.owner() {
+                Some(Owner::Company(c)) => PreviewCell::Company(c),
+                _ => PreviewCell::Land
+            },
+            _ => PreviewCell::Land
+        }
+    }
+
+    /// Colour on the 256 colours ANSI palette
+    fn ansi_colour(&self) -> u8 {
+        match self {
+            PreviewCell::Void => 16,
+            PreviewCell::Land => 28,
+            PreviewCell::Water => 25,
+            PreviewCell::Town => 173,
+            PreviewCell::Road => 250,
+            PreviewCell::Rail => 238,
+            PreviewCell::Company(c) => COMPANY_COLOURS[*c as usize % COMPANY_COLOURS.len()]
+        }
+    }
+}
+
+/// The 16 company colours of OpenTTD, on the 256 colours ANSI palette
+const COMPANY_COLOURS: [u8; 16] = [18, 114, 211, 220, 160, 117, 34, 22, 27, 230, 139, 92, 208, 94, 245, 231];
+
+/// Downsampled map
+#[derive(Debug, PartialEq)]
+pub struct MapPreview {
+    pub columns: u32,
+    pub rows: u32,
+    /// Cells, row by row
+    pub cells: Vec<PreviewCell>,
+}
+
+impl MapPreview {
+
+    /// Downsample the region of the map to fit the columns
+    pub fn render(map: &Map, region: Region, columns: u32) -> Result<MapPreview, Error> {
+
+        if region.width == 0 || region.height == 0 || columns == 0 {
+            return Err(Error::InvalidArgument("Empty region".to_string()));
+        }
+
+        if region.x.saturating_add(region.width) > map.width || region.y.saturating_add(region.height) > map.height {
+            return Err(Error::InvalidArgument(format!("Region outside of the {}x{} map", map.width, map.height)));
+        }
+
+        // Tiles covered by each cell, on both axis
+        let step = region.width.div_ceil(columns);
+        let out_columns = region.width.div_ceil(step);
+        let out_rows = region.height.div_ceil(step);
+
+        let mut cells = vec![PreviewCell::Void; (out_columns * out_rows) as usize];
+
+        for y in 0..region.height {
+            for x in 0..region.width {
+                let raw = map.tile(region.x + x, region.y + y)
+                    .ok_or_else(|| Error::DataCorruption(format!("Tile {}x{} outside of the map", x, y)))?;
+                let kind = PreviewCell::from_tile(&Tile::try_from(raw)?);
+
+                let cell = &mut cells[((y / step) * out_columns + x / step) as usize];
+                if kind > *cell {
+                    *cell = kind;
+                }
+            }
+        }
+
+        Ok(MapPreview {
+            columns: out_columns,
+            rows: out_rows,
+            cells
+        })
+    }
+
+    /// Return the cell at column and row
+    pub fn cell(&self, column: u32, row: u32) -> Option<PreviewCell> {
+        if column >= self.columns || row >= self.rows {
+            return None;
+        }
+
+        Some(self.cells[(row * self.columns + column) as usize])
+    }
+}
+
+impl fmt::Display for MapPreview {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in (0..self.rows).step_by(2) {
+            for column in 0..self.columns {
+                let top = self.cell(column, row).unwrap_or(PreviewCell::Void);
+                let bottom = self.cell(column, row + 1).unwrap_or(PreviewCell::Void);
+
+                write!(f, "\x1b[38;5;{}m\x1b[48;5;{}m\u{2580}", top.ansi_colour(), bottom.ansi_colour())?;
+            }
+            writeln!(f, "\x1b[0m")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::map::Map;
+    use crate::preview::*;
+
+    /// 4x2 map with: void, water, a town road and a company station
+    /// on the first row, and clear land on the second
+    fn map() -> Map {
+        Map {
+            width: 4,
+            height: 2,
+            tile_type: vec![0x70, 0x60, 0x20, 0x50, 0x00, 0x00, 0x00, 0x00],
+            tile_height: vec![0; 8],
+            m1: vec![0x00, 0x11, 0x0f, 0x02, 0x10, 0x10, 0x10, 0x10],
+            m2: vec![0; 8],
+            m3: vec![0; 8],
+            m4: vec![0; 8],
+            m5: vec![0, 0, 0x05, 0, 0, 0, 0, 0],
+            m6: vec![0; 8],
+            m7: vec![0; 8],
+            m8: vec![0; 8],
+        }
+    }
+
+    #[test]
+    fn region() -> Result<(), String> {
+
+        assert_eq!(Region { x: 10, y: 20, width: 30, height: 40 }, "10,20,30,40".parse::<Region>().map_err(|e| e.to_string())?);
+        assert!("10,20,30".parse::<Region>().is_err());
+        assert!("10,a,30,40".parse::<Region>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn downsample() -> Result<(), String> {
+
+        let map = map();
+
+        let full = MapPreview::render(&map, Region::whole(&map), 80)
+            .map_err(|e| e.to_string())?;
+        assert_eq!(4, full.columns);
+        assert_eq!(2, full.rows);
+        assert_eq!(Some(PreviewCell::Water), full.cell(1, 0));
+        assert_eq!(Some(PreviewCell::Road), full.cell(2, 0));
+        assert_eq!(Some(PreviewCell::Company(2)), full.cell(3, 0));
+        assert_eq!(Some(PreviewCell::Land), full.cell(0, 1));
+
+        let half = MapPreview::render(&map, Region::whole(&map), 2)
+            .map_err(|e| e.to_string())?;
+        assert_eq!(2, half.columns);
+        assert_eq!(1, half.rows);
+        assert_eq!(vec![PreviewCell::Water, PreviewCell::Company(2)], half.cells);
+
+        let zoom = MapPreview::render(&map, "1,0,2,1".parse().map_err(|e: Error| e.to_string())?, 80)
+            .map_err(|e| e.to_string())?;
+        assert_eq!(vec![PreviewCell::Water, PreviewCell::Road], zoom.cells);
+
+        assert!(MapPreview::render(&map, "3,0,2,1".parse().map_err(|e: Error| e.to_string())?, 80).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn ansi() -> Result<(), String> {
+
+        let map = map();
+
+        let preview = MapPreview::render(&map, Region::whole(&map), 80)
+            .map_err(|e| e.to_string())?;
+        let text = preview.to_string();
+
+        assert_eq!(1, text.lines().count());
+        assert!(text.starts_with("\x1b[38;5;16m\x1b[48;5;28m\u{2580}"));
+        assert!(text.ends_with("\x1b[0m\n"));
+
+        Ok(())
+    }
+}
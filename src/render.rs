@@ -0,0 +1,241 @@
+//! Map rendering API
+//!
+//! Draw the map tiles, one pixel per tile, and
+//! encode the result as a PNG image.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+use crate::bitmath::gb;
+use crate::error::Error;
+use crate::map::Map;
+use crate::tile::{Tile, Owner};
+
+type Colour = [u8; 3];
+
+const VOID: Colour = [0x00, 0x00, 0x00];
+const WATER: Colour = [0x2c, 0x55, 0xa8];
+const LOW_LAND: Colour = [0x3c, 0x8c, 0x3c];
+const HIGH_LAND: Colour = [0xd8, 0xd0, 0xb8];
+const RAIL: Colour = [0x30, 0x30, 0x30];
+const ROAD: Colour = [0xa8, 0xa8, 0xa8];
+const STATION: Colour = [0xe8, 0x60, 0x20];
+const HOUSE: Colour = [0xb8, 0x88, 0x58];
+const NO_OWNER: Colour = [0x60, 0x70, 0x60];
+const TOWN: Colour = [0xc8, 0xc8, 0xc8];
+
+/// The 16 company colours of OpenTTD
+const COMPANY_COLOURS: [Colour; 16] = [
+    [0x1c, 0x3c, 0x9c], // Dark blue
+    [0x8c, 0xd4, 0x8c], // Pale green
+    [0xe8, 0x8c, 0xb0], // Pink
+    [0xf0, 0xd8, 0x20], // Yellow
+    [0xc8, 0x10, 0x10], // Red
+    [0x6c, 0xb4, 0xf0], // Light blue
+    [0x28, 0xa8, 0x28], // Green
+    [0x14, 0x58, 0x14], // Dark green
+    [0x20, 0x60, 0xe0], // Blue
+    [0xe8, 0xd8, 0xa8], // Cream
+    [0xa0, 0x58, 0xa0], // Mauve
+    [0x70, 0x20, 0x98], // Purple
+    [0xf0, 0x80, 0x10], // Orange
+    [0x80, 0x50, 0x20], // Brown
+    [0x88, 0x88, 0x88], // Grey
+    [0xf8, 0xf8, 0xf8], // White
+];
+
+/// What will be drawn on the image
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RenderMode {
+    /// Land coloured by the height, and the water
+    Terrain,
+    /// Tiles coloured by the company, or town, that owns them
+    Owners,
+    /// Rails, roads and stations over the terrain
+    Transport,
+    /// Industries over the terrain, one colour per industry
+    Industries,
+}
+
+/// Rendered map, with the RGB pixels
+#[derive(Debug, PartialEq)]
+pub struct MapImage {
+    pub width: u32,
+    pub height: u32,
+    /// Three bytes per pixel, row by row
+    pub pixels: Vec<u8>,
+}
+
+impl MapImage {
+
+    /// Draw the map with the mode
+    pub fn render(map: &Map, mode: RenderMode) -> Result<MapImage, Error> {
+
+        let max_height = map.tile_height.iter().max().copied().unwrap_or(0);
+
+        let mut pixels = Vec::with_capacity(map.tile_type.len() * 3);
+
+        for y in 0..map.height {
+            for x in 0..map.width {
+                let raw = map.tile(x, y)
+                    .ok_or_else(|| Error::DataCorruption(format!("Tile {}x{} outside of the map", x, y)))?;
+                let tile = Tile::try_from(raw)?;
+
+                pixels.extend_from_slice(&tile_colour(&tile, mode, max_height));
+            }
+        }
+
+        Ok(MapImage {
+            width: map.width,
+            height: map.height,
+            pixels
+        })
+    }
+
+    /// Encode the image as PNG
+    pub fn write_png<W: Write>(&self, writer: W) -> Result<(), Error> {
+
+        let mut encoder = png::Encoder::new(writer, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)?;
+
+        Ok(())
+    }
+
+    /// Save the image as a PNG file
+    pub fn save_png(&self, name: String) -> Result<(), Error> {
+        let f = File::create(name)?;
+
+        self.write_png(BufWriter::new(f))
+    }
+}
+
+/// Colour of one tile
+fn tile_colour(tile: &Tile, mode: RenderMode, max_height: u8) -> Colour {
+
+    let terrain = terrain_colour(tile, max_height);
+
+    match mode {
+        RenderMode::Terrain => terrain,
+        RenderMode::Owners => match tile {
+            Tile::Void(_) | Tile::Water(_) => terrain,
+            Tile::House(_) => TOWN,
+            Tile::Industry(_) => NO_OWNER,
+            t => match t.owner() {
+                Some(Owner::Company(c)) => COMPANY_COLOURS[c as usize % COMPANY_COLOURS.len()],
+                Some(Owner::Town) => TOWN,
+                _ => NO_OWNER
+            }
+        },
+        RenderMode::Transport => match tile {
+            Tile::Void(_) | Tile::Water(_) => terrain,
+            Tile::Station(_) => STATION,
+            Tile::House(_) => HOUSE,
+            Tile::TunnelBridge(raw) => match gb(raw.m5 as u32, 2, 2) {
+                0 => RAIL, // TRANSPORT_RAIL
+                1 => ROAD, // TRANSPORT_ROAD
+                _ => terrain
+            },
+            t if t.track_bits().is_some() => RAIL,
+            t if t.road_bits().is_some() => ROAD,
+            _ => faded(terrain)
+        },
+        RenderMode::Industries => match tile {
+            Tile::Void(_) | Tile::Water(_) => terrain,
+            Tile::Industry(raw) => COMPANY_COLOURS[raw.m2 as usize % COMPANY_COLOURS.len()],
+            _ => faded(terrain)
+        }
+    }
+}
+
+/// Colour of the land, by height, or the water
+fn terrain_colour(tile: &Tile, max_height: u8) -> Colour {
+    match tile {
+        Tile::Void(_) => VOID,
+        Tile::Water(_) => WATER,
+        t => {
+            let h = t.height() as u32;
+            let max = (max_height as u32).max(1);
+
+            let mut colour = LOW_LAND;
+            for (i, c) in colour.iter_mut().enumerate() {
+                *c = ((LOW_LAND[i] as u32 * (max - h) + HIGH_LAND[i] as u32 * h) / max) as u8;
+            }
+
+            colour
+        }
+    }
+}
+
+/// Gray and lighter version of the colour, for backgrounds
+fn faded(colour: Colour) -> Colour {
+    let gray = (colour[0] as u32 + colour[1] as u32 + colour[2] as u32) / 3;
+    let c = ((gray + 0xff) / 2) as u8;
+
+    [c, c, c]
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::map::Map;
+    use crate::render::*;
+
+    /// 2x2 map with: void, void, river and a rail
+    fn map() -> Map {
+        Map {
+            width: 2,
+            height: 2,
+            tile_type: vec![0x70, 0x70, 0x60, 0x10],
+            tile_height: vec![0, 0, 0, 1],
+            m1: vec![0x00, 0x00, 0x51, 0x00],
+            m2: vec![0; 4],
+            m3: vec![0; 4],
+            m4: vec![0, 0, 0, 1],
+            m5: vec![0, 0, 0, 10],
+            m6: vec![0; 4],
+            m7: vec![0; 4],
+            m8: vec![0; 4],
+        }
+    }
+
+    #[test]
+    fn modes() -> Result<(), String> {
+
+        let terrain = MapImage::render(&map(), RenderMode::Terrain)
+            .map_err(|e| e.to_string())?;
+        assert_eq!(2, terrain.width);
+        assert_eq!(12, terrain.pixels.len());
+        assert_eq!(VOID, terrain.pixels[0..3]);
+        assert_eq!(WATER, terrain.pixels[6..9]);
+        assert_eq!(HIGH_LAND, terrain.pixels[9..12]);
+
+        let owners = MapImage::render(&map(), RenderMode::Owners)
+            .map_err(|e| e.to_string())?;
+        assert_eq!(COMPANY_COLOURS[0], owners.pixels[9..12]);
+
+        let transport = MapImage::render(&map(), RenderMode::Transport)
+            .map_err(|e| e.to_string())?;
+        assert_eq!(RAIL, transport.pixels[9..12]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn png() -> Result<(), String> {
+
+        let image = MapImage::render(&map(), RenderMode::Terrain)
+            .map_err(|e| e.to_string())?;
+
+        let mut buffer = vec![];
+        image.write_png(&mut buffer)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!([0x89, 0x50, 0x4e, 0x47], buffer[0..4]);
+
+        Ok(())
+    }
+}
@@ -7,6 +7,17 @@ use crate::format::Format;
 use crate::error::Error;
 use crate::company::Company;
 use crate::vehicle::Vehicles;
+use crate::engine::Engine;
+use crate::order::OrderList;
+use crate::timetable::Timetable;
+use crate::map::Map;
+use crate::town::Town;
+use crate::industry::Industry;
+use crate::station::Station;
+use crate::cargo_packet::CargoPacket;
+use crate::link_graph::{LinkGraph, LinkGraphJob, LinkGraphSchedule};
+use crate::settings::GameSettings;
+use crate::date::Date;
 
 /// Save game
 pub struct SaveGame {
@@ -29,7 +40,7 @@ impl SaveGame {
     pub fn load(bf_reader: &mut BufReader<File>) -> Result<SaveGame, Error> {
 
         let format = {
-            let mut tag = [0 as u8; 4];
+            let mut tag = [0_u8; 4];
 
             bf_reader.read_exact(&mut tag)?;
 
@@ -41,7 +52,7 @@ impl SaveGame {
         let mut buffer = vec![];
         bf_reader.read_to_end(&mut buffer)?;
 
-        let raw = lzma::decompress(&mut buffer)?;
+        let raw = lzma::decompress(&buffer)?;
 
         Ok(SaveGame {
             format,
@@ -58,4 +69,72 @@ impl SaveGame {
     pub fn vehicles(&mut self) -> Result<Vehicles, Error> {
         Vehicles::parse(&self.raw)
     }
+
+    /// Return the engines, the models of the vehicles
+    pub fn engines(&mut self) -> Result<Vec<Engine>, Error> {
+        Engine::parse_list(&self.raw)
+    }
+
+    /// Return the order lists, with their vehicles
+    pub fn order_lists(&mut self) -> Result<Vec<OrderList>, Error> {
+        OrderList::parse_list(&self.raw, &Vehicles::parse(&self.raw)?)
+    }
+
+    /// Return the timetables of the order lists
+    pub fn timetables(&mut self) -> Result<Vec<Timetable>, Error> {
+        Timetable::parse_list(&self.raw)
+    }
+
+    /// Return the map tiles
+    pub fn map(&mut self) -> Result<Map, Error> {
+        Map::parse(&self.raw)
+    }
+
+    /// Return the towns list
+    pub fn towns(&mut self) -> Result<Vec<Town>, Error> {
+        let mut towns = Town::parse_list(&self.raw)?;
+        Town::rebuild_population(&mut towns, &Map::parse(&self.raw)?);
+
+        Ok(towns)
+    }
+
+    /// Return the current date of the game
+    pub fn date(&mut self) -> Result<Date, Error> {
+        Date::parse(&self.raw)
+    }
+
+    /// Return the game settings
+    pub fn settings(&mut self) -> Result<GameSettings, Error> {
+        GameSettings::parse(&self.raw)
+    }
+
+    /// Return the industries list
+    pub fn industries(&mut self) -> Result<Vec<Industry>, Error> {
+        Industry::parse_list(&self.raw)
+    }
+
+    /// Return the stations list, with the waypoints and buoys
+    pub fn stations(&mut self) -> Result<Vec<Station>, Error> {
+        Station::parse_list(&self.raw, &Town::parse_list(&self.raw)?, &Map::parse(&self.raw)?)
+    }
+
+    /// Return the cargo packets, waiting on the stations or loaded on the vehicles
+    pub fn cargo_packets(&mut self) -> Result<Vec<CargoPacket>, Error> {
+        CargoPacket::parse_list(&self.raw)
+    }
+
+    /// Return the link graphs of the cargo distribution
+    pub fn link_graphs(&mut self) -> Result<Vec<LinkGraph>, Error> {
+        LinkGraph::parse_list(&self.raw)
+    }
+
+    /// Return the running jobs of the cargo distribution
+    pub fn link_graph_jobs(&mut self) -> Result<Vec<LinkGraphJob>, Error> {
+        LinkGraphJob::parse_list(&self.raw)
+    }
+
+    /// Return the order of the link graphs to compute
+    pub fn link_graph_schedule(&mut self) -> Result<LinkGraphSchedule, Error> {
+        LinkGraphSchedule::parse(&self.raw)
+    }
 }
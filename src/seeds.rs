@@ -16,6 +16,12 @@ pub fn seed_chance8(shift_by: u8, max: usize, seed: u32) -> usize {
     ((gb(seed, shift_by, 8) * (max as u32)) >> 8) as usize
 }
 
+/// Return the an index number from given seed,
+/// using the modulo, for a more even distribution
+pub fn seed_mod_chance(shift_by: u8, max: usize, seed: u32) -> usize {
+    ((seed >> shift_by) % max as u32) as usize
+}
+
 /// Return the an index number from given seed,
 /// but with a limitator
 pub fn seed_chance_bias(shift_by: u8, max: usize, seed: u32, bias: u16) -> Option<usize> {
@@ -0,0 +1,206 @@
+//! Game settings related types
+//!
+//! The settings are saved in the order of the settings table,
+//! only the first ones, until the climate, are decoded.
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/table/settings.ini
+
+use crate::chunk_reader::ChunkReader;
+use crate::error::Error;
+use crate::money::{Money, currencies};
+use crate::table::cargo::{self, CargoSpec};
+use crate::table::engine::{self, EngineSpec};
+use crate::table::industry::{self, IndustrySpec};
+
+/// Climate of the game, the landscape setting
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Climate {
+    Temperate,
+    SubArctic,
+    SubTropical,
+    Toyland,
+}
+
+impl TryFrom<u8> for Climate {
+    type Error = Error;
+
+    fn try_from(landscape: u8) -> Result<Self, Self::Error> {
+        match landscape {
+            0 => Ok(Climate::Temperate),
+            1 => Ok(Climate::SubArctic),
+            2 => Ok(Climate::SubTropical),
+            3 => Ok(Climate::Toyland),
+            l => Err(Error::DataCorruption(format!("Unknown landscape: {}", l)))
+        }
+    }
+}
+
+impl Climate {
+
+    /// Default cargos of the climate, by the cargo id
+    pub fn cargos(&self) -> &'static [Option<CargoSpec>] {
+        match self {
+            Climate::Temperate => cargo::CARGO_TEMPERATE,
+            Climate::SubArctic => cargo::CARGO_SUB_ARCTIC,
+            Climate::SubTropical => cargo::CARGO_SUB_TROPICAL,
+            Climate::Toyland => cargo::CARGO_TOYLAND,
+        }
+    }
+
+    /// Default cargo of the climate with the id
+    pub fn cargo(&self, cargo: u8) -> Option<&'static CargoSpec> {
+        self.cargos().get(cargo as usize)?.as_ref()
+    }
+
+    /// Bit of the climate on the climates of the tables
+    fn mask(&self) -> u8 {
+        match self {
+            Climate::Temperate => industry::IC_TEMPERATE,
+            Climate::SubArctic => industry::IC_SUB_ARCTIC,
+            Climate::SubTropical => industry::IC_SUB_TROPICAL,
+            Climate::Toyland => industry::IC_TOYLAND,
+        }
+    }
+
+    /// Default industry of the climate with the type
+    pub fn industry(&self, industry_type: u8) -> Option<&'static IndustrySpec> {
+        industry::INDUSTRY_SPECS.get(industry_type as usize)
+            .filter(|spec| spec.climates & self.mask() != 0)
+    }
+
+    /// Default engine of the climate with the original id
+    pub fn engine(&self, engine_id: usize) -> Option<&'static EngineSpec> {
+        engine::ENGINE_SPECS.get(engine_id)
+            .filter(|spec| spec.climates & self.mask() != 0)
+    }
+}
+
+/// Game settings, the difficulty and the game creation options
+#[derive(Debug, PartialEq)]
+pub struct GameSettings {
+    /// Max number of AI companies
+    pub max_competitors: u8,
+    /// Number of towns, from very low to custom
+    pub number_towns: u8,
+    /// Density of the industries, from none to high
+    pub industry_density: u8,
+    /// Max amount of money borrowed from the bank
+    pub max_loan: Money,
+    /// Breakdowns of the vehicles, from none to normal
+    pub vehicle_breakdowns: u8,
+    /// Disasters, like the UFOs and the floods
+    pub disasters: bool,
+    /// Language of the town names
+    pub town_name: u8,
+    /// Climate of the map
+    pub climate: Climate,
+}
+
+impl GameSettings {
+
+    /// Parse the game settings
+    pub fn parse(buffer: &[u8]) -> Result<GameSettings, Error> {
+
+        let mut chunk = ChunkReader::find(buffer, "PATS")?; // 50 41 54 53
+
+        let max_competitors = chunk.fetch::<u8>()?; // difficulty.max_no_competitors
+        let number_towns = chunk.fetch::<u8>()?; // difficulty.number_towns
+        let industry_density = chunk.fetch::<u8>()?; // difficulty.industry_density
+        let max_loan = Money::new(chunk.fetch::<u32>()? as i64, currencies::GBP); // difficulty.max_loan
+        chunk.advance::<u8>()?; // difficulty.initial_interest
+        chunk.advance::<u8>()?; // difficulty.vehicle_costs
+        chunk.advance::<u8>()?; // difficulty.competitor_speed
+        let vehicle_breakdowns = chunk.fetch::<u8>()?; // difficulty.vehicle_breakdowns
+        chunk.advance::<u8>()?; // difficulty.subsidy_multiplier
+        chunk.advance::<u8>()?; // difficulty.construction_cost
+        chunk.advance::<u8>()?; // difficulty.terrain_type
+        chunk.advance::<u8>()?; // difficulty.quantity_sea_lakes
+        chunk.advance::<u8>()?; // difficulty.economy
+        chunk.advance::<u8>()?; // difficulty.line_reverse_mode
+        let disasters = chunk.fetch::<u8>()? != 0; // difficulty.disasters
+        chunk.advance::<u8>()?; // difficulty.town_council_tolerance
+        let town_name = chunk.fetch::<u8>()?; // game_creation.town_name
+        let climate = Climate::try_from(chunk.fetch::<u8>()?)?; // game_creation.landscape
+
+        Ok(GameSettings {
+            max_competitors,
+            number_towns,
+            industry_density,
+            max_loan,
+            vehicle_breakdowns,
+            disasters,
+            town_name,
+            climate
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::settings::*;
+
+    #[test]
+    fn base() -> Result<(), String> {
+
+        let settings = GameSettings::parse(&PATS)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(GameSettings {
+            max_competitors: 0,
+            number_towns: 2,
+            industry_density: 5,
+            max_loan: Money::new(300000, currencies::GBP),
+            vehicle_breakdowns: 1,
+            disasters: false,
+            town_name: 0,
+            climate: Climate::Temperate
+        }, settings);
+
+        let mut buffer = PATS.to_vec();
+        buffer[28] = 4;
+        assert!(GameSettings::parse(&buffer).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn tables() {
+
+        assert_eq!("Valuables", Climate::Temperate.cargo(10).unwrap().name);
+        assert_eq!("GOLD", Climate::SubArctic.cargo(10).unwrap().label);
+        assert_eq!(None, Climate::SubArctic.cargo(8));
+        assert_eq!(None, Climate::Temperate.cargo(11));
+        assert_eq!("Fizzy Drinks", Climate::Toyland.cargo(11).unwrap().name);
+
+        let bank = Climate::Temperate.industry(12).unwrap();
+        assert_eq!("Bank", bank.name);
+        assert_eq!(&[10], bank.produced);
+        assert_eq!(None, Climate::SubTropical.industry(12));
+        assert_eq!("Diamond Mine", Climate::SubTropical.industry(17).unwrap().name);
+        assert_eq!(None, Climate::Toyland.industry(37));
+
+        assert_eq!("Kirby Paul Tank (Steam)", Climate::Temperate.engine(0).unwrap().name);
+        assert_eq!(None, Climate::Temperate.engine(1));
+        assert_eq!("Guru X2 Helicopter", Climate::SubArctic.engine(254).unwrap().name);
+        assert_eq!(None, Climate::Toyland.engine(256));
+
+        // The cargos of the industries exist on the climates
+        for climate in [Climate::Temperate, Climate::SubArctic, Climate::SubTropical, Climate::Toyland] {
+            for industry_type in 0..37 {
+                if let Some(spec) = climate.industry(industry_type) {
+                    for cargo in spec.produced.iter().chain(spec.accepted) {
+                        assert!(climate.cargo(*cargo).is_some(), "{} on {:?}", spec.name, climate);
+                    }
+                }
+            }
+        }
+    }
+
+    /// The first settings, until the climate
+    static PATS: [u8; 29] = [
+        0x50, 0x41, 0x54, 0x53, 0x00, 0x00, 0x00, 0x15, 0x00, 0x02, 0x05, 0x00, 0x04, 0x93, 0xe0, 0x02,
+        0x00, 0x02, 0x01, 0x02, 0x00, 0x02, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+}
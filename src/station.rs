@@ -0,0 +1,813 @@
+//! Station related types
+//!
+//! The stations, the waypoints and the buoys share the same
+//! pool, and are saved on the same chunk.
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/saveload/station_sl.cpp
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/station_cmd.cpp
+
+use std::collections::BTreeMap;
+
+use crate::bitmath::gb;
+use crate::cargo_packet::CargoPacket;
+use crate::chunk_reader::ChunkReader;
+use crate::date::Date;
+use crate::error::Error;
+use crate::map::Map;
+use crate::tile::{Tile, Owner};
+use crate::table::airport::{AirportSpec, AIRPORT_SPECS};
+use crate::town::Town;
+
+/// Facilities of a station, saved as flags
+const FACIL_TRAIN: u8 = 0x01;
+const FACIL_TRUCK_STOP: u8 = 0x02;
+const FACIL_BUS_STOP: u8 = 0x04;
+const FACIL_AIRPORT: u8 = 0x08;
+const FACIL_DOCK: u8 = 0x10;
+const FACIL_WAYPOINT: u8 = 0x80;
+
+/// First string of the generated station names, STR_SV_STNAME,
+/// on the string table of the 1.8 release
+const STR_SV_STNAME: u16 = 0x6006;
+
+/// Generated station names, as prefix and suffix of the town name,
+/// from STR_SV_STNAME to STR_SV_STNAME_FALLBACK
+const STATION_NAMES: [(&str, &str); 27] = [
+    ("", ""),
+    ("", " North"),
+    ("", " South"),
+    ("", " East"),
+    ("", " West"),
+    ("", " Central"),
+    ("", " Transfer"),
+    ("", " Halt"),
+    ("", " Valley"),
+    ("", " Heights"),
+    ("", " Woods"),
+    ("", " Lakeside"),
+    ("", " Exchange"),
+    ("", " Airport"),
+    ("", " Oilfield"),
+    ("", " Mines"),
+    ("", " Docks"),
+    ("", " Buoy"),
+    ("", " Waypoint"),
+    ("", " Annexe"),
+    ("", " Sidings"),
+    ("", " Branch"),
+    ("Upper ", ""),
+    ("Lower ", ""),
+    ("", " Heliport"),
+    ("", " Forest"),
+    ("", ", Station"),
+];
+
+/// Index of STR_SV_STNAME_FALLBACK, numbered by the station index
+const STATION_NAME_FALLBACK: usize = 26;
+
+/// No tile, like the stations without airport
+const INVALID_TILE: u32 = 0xFFFF_FFFF;
+
+/// Types of the station tiles, saved on the bits 3..5 of m6
+const STATION_OILRIG: u8 = 4;
+const STATION_DOCK: u8 = 5;
+
+/// Number of cargo types of a game
+const NUM_CARGO: u8 = 32;
+
+/// Status of the goods entries, saved as flags
+const GES_ACCEPTANCE: u8 = 0x01;
+const GES_RATING: u8 = 0x02;
+
+/// Ticks between two updates of the station ratings
+const STATION_RATING_TICKS: u32 = 185;
+
+/// Ticks of a day
+const DAY_TICKS: u32 = 74;
+
+/// Last value of time_since_pickup giving a bonus to the rating
+const PICKUP_BONUS_LIMIT: u8 = 21;
+
+/// Facilities built on a station
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct StationFacilities {
+    pub train: bool,
+    pub truck_stop: bool,
+    pub bus_stop: bool,
+    pub airport: bool,
+    pub dock: bool,
+    /// Waypoints and buoys, without any other facility
+    pub waypoint: bool,
+}
+
+impl From<u8> for StationFacilities {
+    fn from(facilities: u8) -> Self {
+        StationFacilities {
+            train: facilities & FACIL_TRAIN != 0,
+            truck_stop: facilities & FACIL_TRUCK_STOP != 0,
+            bus_stop: facilities & FACIL_BUS_STOP != 0,
+            airport: facilities & FACIL_AIRPORT != 0,
+            dock: facilities & FACIL_DOCK != 0,
+            waypoint: facilities & FACIL_WAYPOINT != 0,
+        }
+    }
+}
+
+/// Cargo handled by a station
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct GoodsEntry {
+    /// Cargo id, on the cargos of the climate
+    pub cargo: u8,
+    /// The station accepts the cargo
+    pub accepted: bool,
+    /// The cargo was picked up at least once, the rating is used
+    pub has_rating: bool,
+    /// Rating of the station for the cargo, from 0 to 255
+    pub rating: u8,
+    /// Rating updates since the last pickup, saturated at 255
+    pub time_since_pickup: u8,
+    /// Max speed of the last vehicle that picked up the cargo
+    pub last_speed: u8,
+    /// Age in years of the last vehicle that picked up the cargo
+    pub last_age: u8,
+    /// Amount of cargo waiting, zero until the packets are counted
+    pub waiting: u32,
+    /// Indexes of the cargo packets waiting on the station
+    pub packets: Vec<usize>,
+}
+
+impl GoodsEntry {
+
+    /// Rating shown by the game, from 0 to 100
+    pub fn rating_percent(&self) -> u8 {
+        ((self.rating as u16 * 101) >> 8) as u8
+    }
+
+    /// Approximate days since the last pickup
+    pub fn days_since_pickup(&self) -> u32 {
+        self.time_since_pickup as u32 * STATION_RATING_TICKS / DAY_TICKS
+    }
+
+    /// Check if the cargo is rated but not picked up for a long
+    /// time, so the rating lost the bonus of the frequent pickups
+    pub fn is_neglected(&self) -> bool {
+        self.has_rating && self.time_since_pickup > PICKUP_BONUS_LIMIT
+    }
+}
+
+/// Airport types, the built-in ones and the NewGRF ones
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AirportType {
+    Small,
+    City,
+    Heliport,
+    Metropolitan,
+    International,
+    Commuter,
+    Helidepot,
+    Intercontinental,
+    Helistation,
+    OilRig,
+    /// Airport of a NewGRF, with the saved type
+    NewGrf(u8),
+}
+
+impl From<u8> for AirportType {
+    fn from(airport_type: u8) -> Self {
+        match airport_type {
+            0 => AirportType::Small,
+            1 => AirportType::City,
+            2 => AirportType::Heliport,
+            3 => AirportType::Metropolitan,
+            4 => AirportType::International,
+            5 => AirportType::Commuter,
+            6 => AirportType::Helidepot,
+            7 => AirportType::Intercontinental,
+            8 => AirportType::Helistation,
+            9 => AirportType::OilRig,
+            t => AirportType::NewGrf(t)
+        }
+    }
+}
+
+impl AirportType {
+
+    /// Default specification of the airport, none for the NewGRF airports
+    pub fn spec(&self) -> Option<&'static AirportSpec> {
+        let index = match self {
+            AirportType::Small => 0,
+            AirportType::City => 1,
+            AirportType::Heliport => 2,
+            AirportType::Metropolitan => 3,
+            AirportType::International => 4,
+            AirportType::Commuter => 5,
+            AirportType::Helidepot => 6,
+            AirportType::Intercontinental => 7,
+            AirportType::Helistation => 8,
+            AirportType::OilRig => 9,
+            AirportType::NewGrf(_) => return None
+        };
+
+        AIRPORT_SPECS.get(index)
+    }
+}
+
+/// Direction of the airport, the built-in ones face north
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AirportRotation {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl TryFrom<u8> for AirportRotation {
+    type Error = Error;
+
+    fn try_from(direction: u8) -> Result<Self, Self::Error> {
+        match direction {
+            0 => Ok(AirportRotation::North),
+            2 => Ok(AirportRotation::East),
+            4 => Ok(AirportRotation::South),
+            6 => Ok(AirportRotation::West),
+            d => Err(Error::DataCorruption(format!("Unknown airport rotation: {}", d)))
+        }
+    }
+}
+
+/// Airport of a station
+#[derive(Debug, PartialEq, Clone)]
+pub struct Airport {
+    /// Type of the airport
+    pub airport_type: AirportType,
+    /// Northern tile of the airport
+    pub tile: u32,
+    /// Number of tiles on the X axis, with the rotation
+    pub width: u8,
+    /// Number of tiles on the Y axis, with the rotation
+    pub height: u8,
+    /// Layout of the tiles, only the NewGRF airports have several ones
+    pub layout: u8,
+    /// Direction of the airport
+    pub rotation: AirportRotation,
+    /// Tiles of the hangars, empty until the map width is known
+    pub hangars: Vec<u32>,
+}
+
+impl Airport {
+
+    /// Tiles of the hangars on a map
+    ///
+    /// The hangars of the NewGRF airports are unknown.
+    pub fn hangar_tiles(&self, map_width: u32) -> Vec<u32> {
+        let spec = match self.airport_type.spec() {
+            Some(s) => s,
+            None => return vec![]
+        };
+        let (size_x, size_y) = (spec.size_x as u32, spec.size_y as u32);
+
+        spec.hangars.iter()
+            .map(|&(x, y)| {
+                let (x, y) = (x as u32, y as u32);
+
+                // Rotation of the offset around the northern tile
+                let (dx, dy) = match self.rotation {
+                    AirportRotation::North => (x, y),
+                    AirportRotation::East => (y, size_x - 1 - x),
+                    AirportRotation::South => (size_x - 1 - x, size_y - 1 - y),
+                    AirportRotation::West => (size_y - 1 - y, x),
+                };
+
+                self.tile + dy * map_width + dx
+            })
+            .collect()
+    }
+}
+
+/// Station informations
+#[derive(Debug, PartialEq)]
+pub struct Station {
+    /// Index of the station on the pool
+    pub index: usize,
+    /// Station name, custom or generated
+    pub name: String,
+    /// Tile index of the station sign
+    pub xy: u32,
+    /// Index of the closest town, used on the generated name
+    pub town: Option<usize>,
+    /// Owner of the station, nobody for the oil rigs
+    pub owner: Owner,
+    /// Facilities of the station
+    pub facilities: StationFacilities,
+    /// Date of the construction
+    pub build_date: Date,
+    /// Tile indexes of the station parts
+    pub tiles: Vec<u32>,
+    /// Airport of the station
+    pub airport: Option<Airport>,
+    /// Tiles of the docks, with the oil rigs
+    pub dock_tiles: Vec<u32>,
+    /// Cargos accepted, rated or waiting on the station
+    pub goods: Vec<GoodsEntry>,
+}
+
+impl Station {
+
+    /// Parse all the stations, waypoints and buoys
+    ///
+    /// The towns are needed by the generated names, and the
+    /// tiles are only found on the map.
+    pub fn parse_list(buffer: &[u8], towns: &[Town], map: &Map) -> Result<Vec<Station>, Error> {
+
+        let mut chunk = ChunkReader::find(buffer, "STNN")?; // 53 54 4e 4e
+
+        let mut stations = vec![];
+
+        loop {
+            // Empty slices are free indexes of the pool
+            if chunk.gamma > 0 {
+                stations.push(Station::parse(&mut chunk, towns)?);
+            }
+
+            match chunk.advance_slice()? {
+                Some(c) => chunk = c,
+                None => break
+            }
+        }
+
+        let mut tiles = Station::list_tiles(map);
+        for station in stations.iter_mut() {
+            station.tiles = tiles.remove(&station.index).unwrap_or_default();
+
+            if station.facilities.dock {
+                station.dock_tiles = station.tiles.iter()
+                    .copied()
+                    .filter(|t| matches!(gb(map.m6[*t as usize] as u32, 3, 3) as u8, STATION_DOCK | STATION_OILRIG))
+                    .collect();
+            }
+
+            if let Some(airport) = station.airport.as_mut() {
+                airport.hangars = airport.hangar_tiles(map.width);
+            }
+        }
+
+        let packets = CargoPacket::parse_list(buffer)?;
+        for goods in stations.iter_mut().flat_map(|s| s.goods.iter_mut()) {
+            goods.waiting = CargoPacket::find(&packets, &goods.packets).iter()
+                .map(|p| p.count as u32)
+                .sum();
+        }
+
+        Ok(stations)
+    }
+
+    /// Parse the station information, without the tiles, the
+    /// hangars and the amount of waiting cargo
+    ///
+    /// The generated names of the stations of unknown towns
+    /// fall back to the index of the station.
+    pub fn parse(chunk: &mut ChunkReader, towns: &[Town]) -> Result<Station, Error> {
+
+        let index = chunk.slice_idx;
+        chunk.advance::<u8>()?; // station or waypoint
+
+        // Fields of the base station
+        let xy = chunk.fetch::<u32>()?; // xy
+        let town = (chunk.fetch::<u32>()? as usize).checked_sub(1); // town
+        let string_id = chunk.fetch::<u16>()?; // string_id
+        let name = chunk.fetch::<String>()?; // name
+        chunk.advance::<u8>()?; // delete_ctr
+        let owner = Owner::from(chunk.fetch::<u8>()?); // owner
+        let facilities = StationFacilities::from(chunk.fetch::<u8>()?); // facilities
+        let build_date = Date(chunk.fetch::<i32>()?); // build_date
+        chunk.advance::<u16>()?; // random_bits
+        chunk.advance::<u8>()?; // waiting_triggers
+        chunk.advance::<u8>()?; // num_specs
+
+        // Number of the waypoint, when the town has several ones
+        let town_cn = if facilities.waypoint {
+            chunk.fetch::<u16>()? // town_cn
+        } else {
+            0
+        };
+
+        let (airport, goods) = if facilities.waypoint {
+            (None, vec![])
+        } else {
+            chunk.advance::<u32>()?; // train_station.tile
+            chunk.advance::<u8>()?; // train_station.w
+            chunk.advance::<u8>()?; // train_station.h
+            chunk.advance::<u32>()?; // bus_stops
+            chunk.advance::<u32>()?; // truck_stops
+            chunk.advance::<u32>()?; // dock_tile
+
+            let airport = Station::parse_airport(chunk)?;
+
+            (airport.filter(|_| facilities.airport), Station::parse_goods(chunk)?)
+        };
+
+        let name = if !name.is_empty() {
+            name
+        } else {
+            match towns.iter().find(|t| Some(t.index) == town) {
+                Some(t) => generate_name(string_id, &t.name, index, town_cn)?,
+                None => format!("#{}", index)
+            }
+        };
+
+        Ok(Station {
+            index,
+            name,
+            xy,
+            town,
+            owner,
+            facilities,
+            build_date,
+            tiles: vec![],
+            airport,
+            dock_tiles: vec![],
+            goods
+        })
+    }
+
+    /// Parse the airport, saved even on the stations without one
+    fn parse_airport(chunk: &mut ChunkReader) -> Result<Option<Airport>, Error> {
+
+        let tile = chunk.fetch::<u32>()?; // airport.tile
+        let width = chunk.fetch::<u8>()?; // airport.w
+        let height = chunk.fetch::<u8>()?; // airport.h
+        let airport_type = chunk.fetch::<u8>()?; // airport.type
+        let layout = chunk.fetch::<u8>()?; // airport.layout
+        chunk.advance::<i64>()?; // airport.flags
+        let rotation = chunk.fetch::<u8>()?; // airport.rotation
+        chunk.advance::<u32>()?; // airport.psa
+
+        if tile == INVALID_TILE {
+            return Ok(None);
+        }
+
+        Ok(Some(Airport {
+            airport_type: AirportType::from(airport_type),
+            tile,
+            width,
+            height,
+            layout,
+            rotation: AirportRotation::try_from(rotation)?,
+            hangars: vec![]
+        }))
+    }
+
+    /// Parse the goods entries, after the fields of the station
+    fn parse_goods(chunk: &mut ChunkReader) -> Result<Vec<GoodsEntry>, Error> {
+
+        chunk.advance::<u8>()?; // indtype
+        chunk.advance::<u8>()?; // time_since_load
+        chunk.advance::<u8>()?; // time_since_unload
+        chunk.advance::<u8>()?; // last_vehicle_type
+        chunk.advance::<u8>()?; // had_vehicle_of_type
+        let loading_vehicles = chunk.fetch::<u32>()?; // loading_vehicles
+        chunk.fetch_array::<u32>(loading_vehicles as usize)?;
+        chunk.advance::<u32>()?; // always_accepted
+
+        let mut goods = vec![];
+
+        for cargo in 0..NUM_CARGO {
+            let status = chunk.fetch::<u8>()?; // status
+            let time_since_pickup = chunk.fetch::<u8>()?; // time_since_pickup
+            let rating = chunk.fetch::<u8>()?; // rating
+            let last_speed = chunk.fetch::<u8>()?; // last_speed
+            let last_age = chunk.fetch::<u8>()?; // last_age
+            chunk.advance::<u8>()?; // amount_fract
+            let num_dests = chunk.fetch::<u32>()?; // _num_dests
+            chunk.advance::<u32>()?; // cargo.reserved_count
+            chunk.advance::<u16>()?; // link_graph
+            chunk.advance::<u16>()?; // node
+            let num_flows = chunk.fetch::<u32>()?; // _num_flows
+            chunk.advance::<u32>()?; // max_waiting_cargo
+
+            for _ in 0..num_flows {
+                chunk.advance::<u16>()?; // source
+                chunk.advance::<u16>()?; // via
+                chunk.advance::<u32>()?; // share
+                chunk.advance::<u8>()?; // restricted
+            }
+
+            // Waiting packets, by the next station
+            let mut packets = vec![];
+            for _ in 0..num_dests {
+                chunk.advance::<u16>()?; // next station
+                let count = chunk.fetch::<u32>()?;
+                // The references are saved as the index plus one
+                packets.extend(chunk.fetch_array::<u32>(count as usize)?
+                    .into_iter()
+                    .filter_map(|p| (p as usize).checked_sub(1)));
+            }
+
+            if status != 0 || !packets.is_empty() {
+                goods.push(GoodsEntry {
+                    cargo,
+                    accepted: status & GES_ACCEPTANCE != 0,
+                    has_rating: status & GES_RATING != 0,
+                    rating,
+                    time_since_pickup,
+                    last_speed,
+                    last_age,
+                    waiting: 0,
+                    packets
+                });
+            }
+        }
+
+        Ok(goods)
+    }
+
+    /// List the station tiles of the map, by the station index
+    pub fn list_tiles(map: &Map) -> BTreeMap<usize, Vec<u32>> {
+
+        let mut tiles: BTreeMap<usize, Vec<u32>> = BTreeMap::new();
+
+        for y in 0..map.height {
+            for x in 0..map.width {
+                let i = (y * map.width + x) as usize;
+
+                // The type is on the bits 4..7
+                if gb(map.tile_type[i] as u32, 4, 4) != 5 {
+                    continue;
+                }
+
+                let station = map.tile(x, y)
+                    .and_then(|raw| Tile::try_from(raw).ok())
+                    .and_then(|tile| tile.station_index());
+
+                if let Some(station) = station {
+                    tiles.entry(station as usize).or_default().push(i as u32);
+                }
+            }
+        }
+
+        tiles
+    }
+
+    /// Check if the station is a waypoint or a buoy
+    pub fn is_waypoint(&self) -> bool {
+        self.facilities.waypoint
+    }
+}
+
+/// Name of the station, like the game generates it
+///
+/// The waypoints and buoys of a town after the first one
+/// are numbered.
+fn generate_name(string_id: u16, town: &str, index: usize, town_cn: u16) -> Result<String, Error> {
+
+    let part = string_id.wrapping_sub(STR_SV_STNAME) as usize;
+
+    let (prefix, suffix) = STATION_NAMES.get(part)
+        .ok_or_else(|| Error::TypeNotSupportedYet("Station name".to_string(), string_id))?;
+
+    Ok(match part {
+        STATION_NAME_FALLBACK => format!("{}{} #{}", town, suffix, index),
+        _ if town_cn > 0 => format!("{}{}{} #{}", prefix, town, suffix, town_cn + 1),
+        _ => format!("{}{}{}", prefix, town, suffix)
+    })
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::station::*;
+
+    #[test]
+    fn base() -> Result<(), String> {
+
+        let towns = vec![Town { index: 258, name: "Petfield".to_string(), ..Town::default() }];
+
+        let mut chunk = ChunkReader::find(&STNN, "STNN")
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(Station {
+            index: 0,
+            name: "Petfield North".to_string(),
+            xy: 866333,
+            town: Some(258),
+            owner: Owner::Company(0),
+            facilities: StationFacilities { train: true, bus_stop: true, ..StationFacilities::default() },
+            build_date: Date(716113),
+            tiles: vec![],
+            airport: None,
+            dock_tiles: vec![],
+            goods: vec![]
+        }, Station {
+            goods: vec![],
+            ..Station::parse(&mut chunk, &towns).map_err(|e| e.to_string())?
+        });
+
+        let mut chunk = chunk.advance_slice()
+            .map_err(|e| e.to_string())?
+            .ok_or("Missing buoy")?;
+
+        let buoy = Station::parse(&mut chunk, &towns)
+            .map_err(|e| e.to_string())?;
+        assert_eq!(1, buoy.index);
+        assert_eq!("Petfield Buoy", buoy.name);
+        assert_eq!(Owner::None, buoy.owner);
+        assert!(buoy.is_waypoint());
+        assert!(buoy.facilities.dock);
+        assert!(buoy.goods.is_empty());
+
+        // Without the town of the generated name
+        let mut chunk = ChunkReader::find(&STNN, "STNN")
+            .map_err(|e| e.to_string())?;
+        let station = Station::parse(&mut chunk, &[])
+            .map_err(|e| e.to_string())?;
+        assert_eq!("#0", station.name);
+
+        Ok(())
+    }
+
+    #[test]
+    fn goods() -> Result<(), String> {
+
+        let towns = vec![Town { index: 258, name: "Petfield".to_string(), ..Town::default() }];
+
+        let mut chunk = ChunkReader::find(&STNN, "STNN")
+            .map_err(|e| e.to_string())?;
+        let station = Station::parse(&mut chunk, &towns)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(vec![0, 2, 4, 5, 6, 9, 10], station.goods.iter().map(|g| g.cargo).collect::<Vec<u8>>());
+        assert!(station.goods.iter().all(|g| g.accepted));
+
+        let passengers = &station.goods[0];
+        assert_eq!(GoodsEntry {
+            cargo: 0,
+            accepted: true,
+            has_rating: true,
+            rating: 83,
+            time_since_pickup: 8,
+            last_speed: 144,
+            last_age: 2,
+            waiting: 0,
+            packets: vec![229, 226, 175, 49, 243, 131, 15]
+        }, *passengers);
+        assert_eq!(32, passengers.rating_percent());
+        assert_eq!(20, passengers.days_since_pickup());
+        assert!(!passengers.is_neglected());
+
+        // Only accepted, never picked up
+        let coal = &station.goods[3];
+        assert!(!coal.has_rating);
+        assert_eq!(175, coal.rating);
+        assert!(coal.packets.is_empty());
+        assert!(!coal.is_neglected());
+
+        let neglected = GoodsEntry { time_since_pickup: 40, ..passengers.clone() };
+        assert!(neglected.is_neglected());
+
+        Ok(())
+    }
+
+    #[test]
+    fn airports() -> Result<(), String> {
+
+        assert_eq!(AirportType::OilRig, AirportType::from(9));
+        assert_eq!(AirportType::NewGrf(10), AirportType::from(10));
+        assert!(AirportRotation::try_from(1).is_err());
+        assert_eq!(Some("Commuter Airport"), AirportType::Commuter.spec().map(|s| s.name));
+        assert!(AirportType::Helistation.spec().is_some_and(|s| s.helicopters_only));
+        assert_eq!(None, AirportType::NewGrf(10).spec());
+
+        // Commuter airport of 5x4 tiles, with the hangar on the eastern corner
+        let mut airport = Airport {
+            airport_type: AirportType::Commuter,
+            tile: 10 * 256 + 20,
+            width: 5,
+            height: 4,
+            layout: 0,
+            rotation: AirportRotation::North,
+            hangars: vec![]
+        };
+        assert_eq!(vec![10 * 256 + 24], airport.hangar_tiles(256));
+
+        airport.rotation = AirportRotation::East;
+        assert_eq!(vec![10 * 256 + 20], airport.hangar_tiles(256));
+
+        airport.rotation = AirportRotation::South;
+        assert_eq!(vec![13 * 256 + 20], airport.hangar_tiles(256));
+
+        airport.rotation = AirportRotation::West;
+        assert_eq!(vec![14 * 256 + 23], airport.hangar_tiles(256));
+
+        airport.airport_type = AirportType::NewGrf(10);
+        assert!(airport.hangar_tiles(256).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn names() -> Result<(), String> {
+
+        assert_eq!("Petfield", generate_name(0x6006, "Petfield", 3, 0).map_err(|e| e.to_string())?);
+        assert_eq!("Petfield Oilfield", generate_name(0x6014, "Petfield", 3, 0).map_err(|e| e.to_string())?);
+        assert_eq!("Upper Petfield", generate_name(0x601C, "Petfield", 3, 0).map_err(|e| e.to_string())?);
+        assert_eq!("Petfield Buoy #2", generate_name(0x6017, "Petfield", 3, 1).map_err(|e| e.to_string())?);
+        assert_eq!("Petfield, Station #3", generate_name(0x6020, "Petfield", 3, 0).map_err(|e| e.to_string())?);
+        assert!(generate_name(0x6021, "Petfield", 3, 0).is_err());
+        assert!(generate_name(0x1000, "Petfield", 3, 0).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn tiles() {
+
+        // 3x2 map with a station of two tiles, a buoy and clear land
+        let map = Map {
+            width: 3,
+            height: 2,
+            tile_type: vec![0x50, 0x50, 0x00, 0x00, 0x60, 0x50],
+            tile_height: vec![0; 6],
+            m1: vec![0; 6],
+            m2: vec![4, 4, 0, 0, 0, 9],
+            m3: vec![0; 6],
+            m4: vec![0; 6],
+            m5: vec![0; 6],
+            m6: vec![0; 6],
+            m7: vec![0; 6],
+            m8: vec![0; 6],
+        };
+
+        let tiles = Station::list_tiles(&map);
+
+        assert_eq!(2, tiles.len());
+        assert_eq!(vec![0, 1], tiles[&4]);
+        assert_eq!(vec![5], tiles[&9]);
+    }
+
+    /// A train station, and a buoy, of the example save
+    static STNN: [u8; 991] = [
+        0x53, 0x54, 0x4e, 0x4e, 0x01, 0x83, 0xb8, 0x00, 0x00, 0x0d, 0x38, 0x1d, 0x00, 0x00, 0x01, 0x03,
+        0x60, 0x07, 0x00, 0x6f, 0x00, 0x05, 0x00, 0x0a, 0xed, 0x51, 0x5e, 0xad, 0x00, 0x00, 0x00, 0x0d,
+        0x38, 0x1d, 0x02, 0x07, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x08, 0x0a, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x25, 0x0f, 0x08, 0x53, 0x90, 0x02, 0xac, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x37, 0xff, 0xff, 0x00, 0x00,
+        0x00, 0x07, 0x00, 0x00, 0x00, 0xe6, 0x00, 0x00, 0x00, 0xe3, 0x00, 0x00, 0x00, 0xb0, 0x00, 0x00,
+        0x00, 0x32, 0x00, 0x00, 0x00, 0xf4, 0x00, 0x00, 0x00, 0x84, 0x00, 0x00, 0x00, 0x10, 0x00, 0xff,
+        0xaf, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x08, 0x60, 0x90, 0x02, 0xc3, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x15, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x3e, 0x00, 0xff, 0xaf, 0x00,
+        0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xff, 0xaf, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0xff, 0xaf, 0x00, 0x18, 0x65, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x43, 0x01, 0xff, 0xaf, 0x00, 0xff, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xaf, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xaf, 0x90, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xff, 0xaf, 0x00, 0xff, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0xff, 0xaf, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xaf, 0x00,
+        0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xaf, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xff, 0xaf, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff,
+        0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xaf, 0x00, 0xff, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xaf, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff,
+        0xaf, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xaf, 0x00, 0xff, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0xff, 0xaf, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xaf, 0x00,
+        0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xaf, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xff, 0xaf, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff,
+        0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xaf, 0x00, 0xff, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xaf, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff,
+        0xaf, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xaf, 0x00, 0xff, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0xff, 0xaf, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xaf, 0x00,
+        0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xaf, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xff, 0xaf, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff,
+        0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xaf, 0x00, 0xff, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xaf, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x80,
+        0x00, 0x0d, 0x0c, 0x06, 0x00, 0x00, 0x01, 0x03, 0x60, 0x17, 0x00, 0x00, 0x10, 0x90, 0x00, 0x0b,
+        0x04, 0xa9, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00,
+    ];
+}
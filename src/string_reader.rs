@@ -12,6 +12,7 @@ static TAB_SIZE_BITS: u8 = 11;
 pub type StringID = u16;
 pub type StringTab = u16;
 
+#[derive(Clone, Copy)]
 pub struct OpenString {
     pub id: StringID,
     pub id_param: u32,
@@ -45,14 +46,15 @@ impl OpenString {
 
     /// Special string generation
     fn to_special_string(self) -> Result<String, Error> {
-        let tp = self.index - 0xE4;
+        let tp = self.index.wrapping_sub(0xE4);
 
         match tp {
             // President name
             3 => PresidentName::generate(0, self.id_param),
-            // Town name
-            6 => TownName::generate(tp - 6, self.id_param),
-            _ => Err(Error::TypeNotSupportedYet("Special string".to_string(), tp))
+            // Company name, from SPECSTR_COMPANY_NAME_START to
+            // the last town name language
+            tp if (6..6 + TOWNNAME_LANGUAGES).contains(&tp) => CompanyName::generate(tp - 6, self.id_param),
+            _ => Err(Error::TypeNotSupportedYet("Special string".to_string(), self.id))
         }
     }
 }
@@ -72,3 +74,28 @@ fn get_string_tab(id: StringID) -> StringTab {
         _ => tab
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use super::OpenString;
+
+    #[test]
+    fn company_names() -> Result<(), String> {
+
+        // SPECSTR_COMPANY_NAME_START, english original
+        let name = OpenString::new(0x70EA, 2200570571).to_string()
+            .map_err(|e| e.to_string())?;
+        assert_eq!("Petfield Transport".to_string(), name);
+
+        // French
+        let name = OpenString::new(0x70EB, 0xdeadbeef).to_string()
+            .map_err(|e| e.to_string())?;
+        assert_eq!("Tours Transport".to_string(), name);
+
+        // After the last language
+        assert!(OpenString::new(0x70EA + 21, 0).to_string().is_err());
+
+        Ok(())
+    }
+}
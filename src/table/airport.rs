@@ -0,0 +1,35 @@
+//! Openttd default airports table
+//!
+//! Indexed by the airport type, the sizes and the hangars
+//! are the ones of the airport facing north.
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/table/airport_defaults.h
+
+/// Built-in airport
+#[derive(Debug, PartialEq)]
+pub struct AirportSpec {
+    /// English name
+    pub name: &'static str,
+    /// Number of tiles on the X axis
+    pub size_x: u8,
+    /// Number of tiles on the Y axis
+    pub size_y: u8,
+    /// Position of the hangars, from the northern tile
+    pub hangars: &'static [(u8, u8)],
+    /// Only the helicopters can land
+    pub helicopters_only: bool,
+}
+
+pub static AIRPORT_SPECS: &[AirportSpec] = &[
+	  AirportSpec { name: "Small Airport", size_x: 4, size_y: 3, hangars: &[(3, 0)], helicopters_only: false },
+	  AirportSpec { name: "City Airport", size_x: 6, size_y: 6, hangars: &[(5, 0)], helicopters_only: false },
+	  AirportSpec { name: "Heliport", size_x: 1, size_y: 1, hangars: &[], helicopters_only: true },
+	  AirportSpec { name: "Metropolitan Airport", size_x: 6, size_y: 6, hangars: &[(5, 0)], helicopters_only: false },
+	  AirportSpec { name: "International Airport", size_x: 7, size_y: 7, hangars: &[(0, 3), (6, 1)], helicopters_only: false },
+	  AirportSpec { name: "Commuter Airport", size_x: 5, size_y: 4, hangars: &[(4, 0)], helicopters_only: false },
+	  AirportSpec { name: "Helidepot", size_x: 2, size_y: 2, hangars: &[(1, 0)], helicopters_only: true },
+	  AirportSpec { name: "Intercontinental Airport", size_x: 9, size_y: 11, hangars: &[(0, 5), (8, 4)], helicopters_only: false },
+	  AirportSpec { name: "Helistation", size_x: 4, size_y: 2, hangars: &[(0, 0)], helicopters_only: true },
+	  AirportSpec { name: "Oil Rig", size_x: 1, size_y: 1, hangars: &[], helicopters_only: true },
+];
@@ -0,0 +1,77 @@
+//! Openttd default cargos table
+//!
+//! One table per climate, indexed by the cargo id. The passengers
+//! and the mail have the same id on all climates.
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/table/cargo_const.h
+
+/// Built-in cargo
+#[derive(Debug, PartialEq)]
+pub struct CargoSpec {
+    /// Four letters label, unique between the climates
+    pub label: &'static str,
+    /// English name
+    pub name: &'static str,
+    /// Weight of one unit, in sixteenths of a tonne
+    pub weight: u8,
+}
+
+pub static CARGO_TEMPERATE: &[Option<CargoSpec>] = &[
+	  Some(CargoSpec { label: "PASS", name: "Passengers", weight: 1 }),
+	  Some(CargoSpec { label: "COAL", name: "Coal", weight: 16 }),
+	  Some(CargoSpec { label: "MAIL", name: "Mail", weight: 4 }),
+	  Some(CargoSpec { label: "OIL_", name: "Oil", weight: 16 }),
+	  Some(CargoSpec { label: "LVST", name: "Livestock", weight: 3 }),
+	  Some(CargoSpec { label: "GOOD", name: "Goods", weight: 8 }),
+	  Some(CargoSpec { label: "GRAI", name: "Grain", weight: 16 }),
+	  Some(CargoSpec { label: "WOOD", name: "Wood", weight: 16 }),
+	  Some(CargoSpec { label: "IORE", name: "Iron Ore", weight: 16 }),
+	  Some(CargoSpec { label: "STEL", name: "Steel", weight: 16 }),
+	  Some(CargoSpec { label: "VALU", name: "Valuables", weight: 2 }),
+];
+
+pub static CARGO_SUB_ARCTIC: &[Option<CargoSpec>] = &[
+	  Some(CargoSpec { label: "PASS", name: "Passengers", weight: 1 }),
+	  Some(CargoSpec { label: "COAL", name: "Coal", weight: 16 }),
+	  Some(CargoSpec { label: "MAIL", name: "Mail", weight: 4 }),
+	  Some(CargoSpec { label: "OIL_", name: "Oil", weight: 16 }),
+	  Some(CargoSpec { label: "LVST", name: "Livestock", weight: 3 }),
+	  Some(CargoSpec { label: "GOOD", name: "Goods", weight: 8 }),
+	  Some(CargoSpec { label: "WHEA", name: "Wheat", weight: 16 }),
+	  Some(CargoSpec { label: "WOOD", name: "Wood", weight: 16 }),
+	  None,
+	  Some(CargoSpec { label: "PAPR", name: "Paper", weight: 16 }),
+	  Some(CargoSpec { label: "GOLD", name: "Gold", weight: 8 }),
+	  Some(CargoSpec { label: "FOOD", name: "Food", weight: 16 }),
+];
+
+pub static CARGO_SUB_TROPICAL: &[Option<CargoSpec>] = &[
+	  Some(CargoSpec { label: "PASS", name: "Passengers", weight: 1 }),
+	  Some(CargoSpec { label: "RUBR", name: "Rubber", weight: 16 }),
+	  Some(CargoSpec { label: "MAIL", name: "Mail", weight: 4 }),
+	  Some(CargoSpec { label: "OIL_", name: "Oil", weight: 16 }),
+	  Some(CargoSpec { label: "FRUT", name: "Fruit", weight: 16 }),
+	  Some(CargoSpec { label: "GOOD", name: "Goods", weight: 8 }),
+	  Some(CargoSpec { label: "MAIZ", name: "Maize", weight: 16 }),
+	  Some(CargoSpec { label: "WOOD", name: "Wood", weight: 16 }),
+	  Some(CargoSpec { label: "CORE", name: "Copper Ore", weight: 16 }),
+	  Some(CargoSpec { label: "WATR", name: "Water", weight: 16 }),
+	  Some(CargoSpec { label: "DIAM", name: "Diamonds", weight: 2 }),
+	  Some(CargoSpec { label: "FOOD", name: "Food", weight: 16 }),
+];
+
+pub static CARGO_TOYLAND: &[Option<CargoSpec>] = &[
+	  Some(CargoSpec { label: "PASS", name: "Passengers", weight: 1 }),
+	  Some(CargoSpec { label: "SUGR", name: "Sugar", weight: 16 }),
+	  Some(CargoSpec { label: "MAIL", name: "Mail", weight: 4 }),
+	  Some(CargoSpec { label: "TOYS", name: "Toys", weight: 2 }),
+	  Some(CargoSpec { label: "BATT", name: "Batteries", weight: 4 }),
+	  Some(CargoSpec { label: "SWET", name: "Sweets", weight: 5 }),
+	  Some(CargoSpec { label: "TOFF", name: "Toffee", weight: 16 }),
+	  Some(CargoSpec { label: "COLA", name: "Cola", weight: 16 }),
+	  Some(CargoSpec { label: "CTCD", name: "Candyfloss", weight: 16 }),
+	  Some(CargoSpec { label: "BUBL", name: "Bubbles", weight: 1 }),
+	  Some(CargoSpec { label: "PLST", name: "Plastic", weight: 16 }),
+	  Some(CargoSpec { label: "FZDR", name: "Fizzy Drinks", weight: 2 }),
+];
@@ -0,0 +1,285 @@
+//! Openttd default engines table
+//!
+//! Indexed by the original engine id, the trains first, then the
+//! road vehicles, the ships and the aircraft.
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/table/engines.h
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/lang/english.txt
+
+use crate::table::industry::{IC_TEMPERATE, IC_SUB_ARCTIC, IC_SUB_TROPICAL, IC_TOYLAND};
+
+/// First original engine id of each vehicle type
+pub const ENGINE_OFFSETS: [usize; 4] = [0, 116, 204, 215];
+
+/// Built-in engine
+#[derive(Debug, PartialEq)]
+pub struct EngineSpec {
+    /// English name
+    pub name: &'static str,
+    /// Climates with the engine
+    pub climates: u8,
+}
+
+pub static ENGINE_SPECS: &[EngineSpec] = &[
+	  // Trains
+	  EngineSpec { name: "Kirby Paul Tank (Steam)", climates: IC_TEMPERATE },
+	  EngineSpec { name: "MJS 250 (Diesel)", climates: IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Ploddyphut Choo-Choo", climates: IC_TOYLAND },
+	  EngineSpec { name: "Powernaut Choo-Choo", climates: IC_TOYLAND },
+	  EngineSpec { name: "Mightymover Choo-Choo", climates: IC_TOYLAND },
+	  EngineSpec { name: "Ploddyphut Diesel", climates: IC_TOYLAND },
+	  EngineSpec { name: "Powernaut Diesel", climates: IC_TOYLAND },
+	  EngineSpec { name: "Wills 2-8-0 (Steam)", climates: IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Chaney 'Jubilee' (Steam)", climates: IC_TEMPERATE },
+	  EngineSpec { name: "Ginzu 'A4' (Steam)", climates: IC_TEMPERATE },
+	  EngineSpec { name: "SH '8P' (Steam)", climates: IC_TEMPERATE },
+	  EngineSpec { name: "Manley-Morel DMU (Diesel)", climates: IC_TEMPERATE },
+	  EngineSpec { name: "'Dash' (Diesel)", climates: IC_TEMPERATE },
+	  EngineSpec { name: "SH/Hendry '25' (Diesel)", climates: IC_TEMPERATE },
+	  EngineSpec { name: "UU '37' (Diesel)", climates: IC_TEMPERATE },
+	  EngineSpec { name: "Floss '47' (Diesel)", climates: IC_TEMPERATE },
+	  EngineSpec { name: "CS 4000 (Diesel)", climates: IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "CS 2400 (Diesel)", climates: IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Centennial (Diesel)", climates: IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Kelling 3100 (Diesel)", climates: IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Turner Turbo (Diesel)", climates: IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "MJS 1000 (Diesel)", climates: IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "SH '125' (Diesel)", climates: IC_TEMPERATE },
+	  EngineSpec { name: "SH '30' (Electric)", climates: IC_TEMPERATE },
+	  EngineSpec { name: "SH '40' (Electric)", climates: IC_TEMPERATE },
+	  EngineSpec { name: "'T.I.M.' (Electric)", climates: IC_TEMPERATE },
+	  EngineSpec { name: "'AsiaStar' (Electric)", climates: IC_TEMPERATE },
+	  EngineSpec { name: "Passenger Carriage", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL | IC_TOYLAND },
+	  EngineSpec { name: "Mail Van", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL | IC_TOYLAND },
+	  EngineSpec { name: "Coal Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC },
+	  EngineSpec { name: "Oil Tanker", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Livestock Van", climates: IC_TEMPERATE | IC_SUB_ARCTIC },
+	  EngineSpec { name: "Goods Van", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Grain Hopper", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Wood Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Iron Ore Hopper", climates: IC_TEMPERATE },
+	  EngineSpec { name: "Steel Truck", climates: IC_TEMPERATE },
+	  EngineSpec { name: "Armoured Van", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Food Van", climates: IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Paper Truck", climates: IC_SUB_ARCTIC },
+	  EngineSpec { name: "Copper Ore Hopper", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Water Tanker", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Fruit Truck", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Rubber Truck", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Sugar Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Candyfloss Hopper", climates: IC_TOYLAND },
+	  EngineSpec { name: "Toffee Hopper", climates: IC_TOYLAND },
+	  EngineSpec { name: "Bubble Van", climates: IC_TOYLAND },
+	  EngineSpec { name: "Cola Tanker", climates: IC_TOYLAND },
+	  EngineSpec { name: "Sweet Van", climates: IC_TOYLAND },
+	  EngineSpec { name: "Toy Van", climates: IC_TOYLAND },
+	  EngineSpec { name: "Battery Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Fizzy Drink Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Plastic Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "'X2001' (Electric)", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "'Millennium Z1' (Electric)", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Wizzowow Z99", climates: IC_TOYLAND },
+	  EngineSpec { name: "Passenger Carriage", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL | IC_TOYLAND },
+	  EngineSpec { name: "Mail Van", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL | IC_TOYLAND },
+	  EngineSpec { name: "Coal Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC },
+	  EngineSpec { name: "Oil Tanker", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Livestock Van", climates: IC_TEMPERATE | IC_SUB_ARCTIC },
+	  EngineSpec { name: "Goods Van", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Grain Hopper", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Wood Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Iron Ore Hopper", climates: IC_TEMPERATE },
+	  EngineSpec { name: "Steel Truck", climates: IC_TEMPERATE },
+	  EngineSpec { name: "Armoured Van", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Food Van", climates: IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Paper Truck", climates: IC_SUB_ARCTIC },
+	  EngineSpec { name: "Copper Ore Hopper", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Water Tanker", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Fruit Truck", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Rubber Truck", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Sugar Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Candyfloss Hopper", climates: IC_TOYLAND },
+	  EngineSpec { name: "Toffee Hopper", climates: IC_TOYLAND },
+	  EngineSpec { name: "Bubble Van", climates: IC_TOYLAND },
+	  EngineSpec { name: "Cola Tanker", climates: IC_TOYLAND },
+	  EngineSpec { name: "Sweet Van", climates: IC_TOYLAND },
+	  EngineSpec { name: "Toy Van", climates: IC_TOYLAND },
+	  EngineSpec { name: "Battery Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Fizzy Drink Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Plastic Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Lev1 'Leviathan' (Electric)", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Lev2 'Cyclops' (Electric)", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Lev3 'Pegasus' (Electric)", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Lev4 'Chimaera' (Electric)", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Wizzowow Rocketeer", climates: IC_TOYLAND },
+	  EngineSpec { name: "Passenger Carriage", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL | IC_TOYLAND },
+	  EngineSpec { name: "Mail Van", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL | IC_TOYLAND },
+	  EngineSpec { name: "Coal Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC },
+	  EngineSpec { name: "Oil Tanker", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Livestock Van", climates: IC_TEMPERATE | IC_SUB_ARCTIC },
+	  EngineSpec { name: "Goods Van", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Grain Hopper", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Wood Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Iron Ore Hopper", climates: IC_TEMPERATE },
+	  EngineSpec { name: "Steel Truck", climates: IC_TEMPERATE },
+	  EngineSpec { name: "Armoured Van", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Food Van", climates: IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Paper Truck", climates: IC_SUB_ARCTIC },
+	  EngineSpec { name: "Copper Ore Hopper", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Water Tanker", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Fruit Truck", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Rubber Truck", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Sugar Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Candyfloss Hopper", climates: IC_TOYLAND },
+	  EngineSpec { name: "Toffee Hopper", climates: IC_TOYLAND },
+	  EngineSpec { name: "Bubble Van", climates: IC_TOYLAND },
+	  EngineSpec { name: "Cola Tanker", climates: IC_TOYLAND },
+	  EngineSpec { name: "Sweet Van", climates: IC_TOYLAND },
+	  EngineSpec { name: "Toy Van", climates: IC_TOYLAND },
+	  EngineSpec { name: "Battery Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Fizzy Drink Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Plastic Truck", climates: IC_TOYLAND },
+	  // Road vehicles
+	  EngineSpec { name: "MPS Regal Bus", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Hereford Leopard Bus", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Foster Bus", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Foster MkII Superbus", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Ploddyphut MkI Bus", climates: IC_TOYLAND },
+	  EngineSpec { name: "Ploddyphut MkII Bus", climates: IC_TOYLAND },
+	  EngineSpec { name: "Ploddyphut MkIII Bus", climates: IC_TOYLAND },
+	  EngineSpec { name: "Balogh Coal Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC },
+	  EngineSpec { name: "Uhl Coal Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC },
+	  EngineSpec { name: "DW Coal Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC },
+	  EngineSpec { name: "MPS Mail Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Reynard Mail Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Perry Mail Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "MightyMover Mail Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Powernaught Mail Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Wizzowow Mail Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Witcombe Oil Tanker", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Foster Oil Tanker", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Perry Oil Tanker", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Talbott Livestock Van", climates: IC_TEMPERATE | IC_SUB_ARCTIC },
+	  EngineSpec { name: "Uhl Livestock Van", climates: IC_TEMPERATE | IC_SUB_ARCTIC },
+	  EngineSpec { name: "Foster Livestock Van", climates: IC_TEMPERATE | IC_SUB_ARCTIC },
+	  EngineSpec { name: "Balogh Goods Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Craighead Goods Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Goss Goods Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Hereford Grain Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Thomas Grain Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Goss Grain Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Witcombe Wood Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Foster Wood Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Moreland Wood Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "MPS Iron Ore Truck", climates: IC_TEMPERATE },
+	  EngineSpec { name: "Uhl Iron Ore Truck", climates: IC_TEMPERATE },
+	  EngineSpec { name: "Chippy Iron Ore Truck", climates: IC_TEMPERATE },
+	  EngineSpec { name: "Balogh Steel Truck", climates: IC_TEMPERATE },
+	  EngineSpec { name: "Uhl Steel Truck", climates: IC_TEMPERATE },
+	  EngineSpec { name: "Kelling Steel Truck", climates: IC_TEMPERATE },
+	  EngineSpec { name: "Balogh Armoured Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Uhl Armoured Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Foster Armoured Truck", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Foster Food Van", climates: IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Perry Food Van", climates: IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Chippy Food Van", climates: IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Uhl Paper Truck", climates: IC_SUB_ARCTIC },
+	  EngineSpec { name: "Balogh Paper Truck", climates: IC_SUB_ARCTIC },
+	  EngineSpec { name: "MPS Paper Truck", climates: IC_SUB_ARCTIC },
+	  EngineSpec { name: "MPS Copper Ore Truck", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Uhl Copper Ore Truck", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Goss Copper Ore Truck", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Uhl Water Tanker", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Balogh Water Tanker", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "MPS Water Tanker", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Balogh Fruit Truck", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Uhl Fruit Truck", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Kelling Fruit Truck", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Balogh Rubber Truck", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "Uhl Rubber Truck", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "RMT Rubber Truck", climates: IC_SUB_TROPICAL },
+	  EngineSpec { name: "MightyMover Sugar Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Powernaught Sugar Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Wizzowow Sugar Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "MightyMover Cola Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Powernaught Cola Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Wizzowow Cola Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "MightyMover Candyfloss Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Powernaught Candyfloss Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Wizzowow Candyfloss Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "MightyMover Toffee Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Powernaught Toffee Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Wizzowow Toffee Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "MightyMover Toy Van", climates: IC_TOYLAND },
+	  EngineSpec { name: "Powernaught Toy Van", climates: IC_TOYLAND },
+	  EngineSpec { name: "Wizzowow Toy Van", climates: IC_TOYLAND },
+	  EngineSpec { name: "MightyMover Sweet Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Powernaught Sweet Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Wizzowow Sweet Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "MightyMover Battery Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Powernaught Battery Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Wizzowow Battery Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "MightyMover Fizzy Drink Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Powernaught Fizzy Drink Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Wizzowow Fizzy Drink Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "MightyMover Plastic Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Powernaught Plastic Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Wizzowow Plastic Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "MightyMover Bubble Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Powernaught Bubble Truck", climates: IC_TOYLAND },
+	  EngineSpec { name: "Wizzowow Bubble Truck", climates: IC_TOYLAND },
+	  // Ships
+	  EngineSpec { name: "MPS Oil Tanker", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "CS-Inc. Oil Tanker", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "MPS Passenger Ferry", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "FFP Passenger Ferry", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Bakewell 300 Hovercraft", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Chugger-Chug Passenger Ferry", climates: IC_TOYLAND },
+	  EngineSpec { name: "Shivershake Passenger Ferry", climates: IC_TOYLAND },
+	  EngineSpec { name: "Yate Cargo ship", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Bakewell Cargo ship", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Mightymover Cargo ship", climates: IC_TOYLAND },
+	  EngineSpec { name: "Powernaut Cargo ship", climates: IC_TOYLAND },
+	  // Aircraft
+	  EngineSpec { name: "Sampson U52", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Coleman Count", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "FFP Dart", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Yate Haugan", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Bakewell Cotswald LB-3", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Bakewell Luckett LB-8", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Bakewell Luckett LB-9", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Bakewell Luckett LB80", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Bakewell Luckett LB-10", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Bakewell Luckett LB-11", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Yate Aerospace YAC 1-11", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Darwin 100", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Darwin 200", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Darwin 300", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Darwin 400", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Darwin 500", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Darwin 600", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Guru Galaxy", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Airtaxi A21", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Airtaxi A31", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Airtaxi A32", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Airtaxi A33", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Yate Aerospace YAe46", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Dinger 100", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "AirTaxi A34-1000", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Yate Z-Shuttle", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Kelling K1", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Kelling K6", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Kelling K7", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Darwin 700", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "FFP Hyperdart 2", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Dinger 200", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Dinger 1000", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Ploddyphut 100", climates: IC_TOYLAND },
+	  EngineSpec { name: "Ploddyphut 500", climates: IC_TOYLAND },
+	  EngineSpec { name: "Flashbang X1", climates: IC_TOYLAND },
+	  EngineSpec { name: "Juggerplane M1", climates: IC_TOYLAND },
+	  EngineSpec { name: "Flashbang Wizzer", climates: IC_TOYLAND },
+	  EngineSpec { name: "Tricario Helicopter", climates: IC_TEMPERATE },
+	  EngineSpec { name: "Guru X2 Helicopter", climates: IC_SUB_ARCTIC | IC_SUB_TROPICAL },
+	  EngineSpec { name: "Powernaut Helicopter", climates: IC_TOYLAND },
+];
@@ -0,0 +1,67 @@
+//! Openttd default houses table
+//!
+//! Indexed by the house type, only the houses up to the shopping
+//! centre, the ones of the temperate climate. The multi-tile
+//! buildings have one entry per tile, the population is on the
+//! northern one.
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/table/build_house.h
+
+/// Built-in house type
+#[derive(Debug, PartialEq)]
+pub struct HouseSpec {
+    /// English name
+    pub name: &'static str,
+    /// Inhabitants of the tile
+    pub population: u32,
+    /// Tiles of the building on his northern tile, zero on the others
+    pub tiles: u8,
+}
+
+pub static HOUSE_SPECS: &[HouseSpec] = &[
+	  HouseSpec { name: "Tall office block", population: 187, tiles: 1 },
+	  HouseSpec { name: "Office block", population: 85, tiles: 1 },
+	  HouseSpec { name: "Small block of flats", population: 40, tiles: 1 },
+	  HouseSpec { name: "Church", population: 5, tiles: 1 },
+	  HouseSpec { name: "Large office block", population: 220, tiles: 1 },
+	  HouseSpec { name: "Large office block", population: 220, tiles: 1 },
+	  HouseSpec { name: "Town houses", population: 30, tiles: 1 },
+	  HouseSpec { name: "Hotel", population: 140, tiles: 2 },
+	  HouseSpec { name: "Hotel", population: 0, tiles: 0 },
+	  HouseSpec { name: "Statue", population: 0, tiles: 1 },
+	  HouseSpec { name: "Fountain", population: 0, tiles: 1 },
+	  HouseSpec { name: "Park", population: 0, tiles: 1 },
+	  HouseSpec { name: "Park", population: 0, tiles: 1 },
+	  HouseSpec { name: "Office block", population: 150, tiles: 1 },
+	  HouseSpec { name: "Shops and offices", population: 95, tiles: 1 },
+	  HouseSpec { name: "Shops and offices", population: 95, tiles: 1 },
+	  HouseSpec { name: "Shops and offices", population: 95, tiles: 1 },
+	  HouseSpec { name: "Modern office building", population: 130, tiles: 1 },
+	  HouseSpec { name: "Warehouse", population: 6, tiles: 1 },
+	  HouseSpec { name: "Office block", population: 110, tiles: 1 },
+	  HouseSpec { name: "Stadium", population: 65, tiles: 4 },
+	  HouseSpec { name: "Stadium", population: 0, tiles: 0 },
+	  HouseSpec { name: "Stadium", population: 0, tiles: 0 },
+	  HouseSpec { name: "Stadium", population: 0, tiles: 0 },
+	  HouseSpec { name: "Old houses", population: 15, tiles: 1 },
+	  HouseSpec { name: "Cottages", population: 12, tiles: 1 },
+	  HouseSpec { name: "Houses", population: 13, tiles: 1 },
+	  HouseSpec { name: "Flats", population: 100, tiles: 1 },
+	  HouseSpec { name: "Tall office block", population: 170, tiles: 1 },
+	  HouseSpec { name: "Shops and offices", population: 100, tiles: 1 },
+	  HouseSpec { name: "Shops and offices", population: 180, tiles: 1 },
+	  HouseSpec { name: "Theatre", population: 35, tiles: 1 },
+	  HouseSpec { name: "Stadium", population: 65, tiles: 4 },
+	  HouseSpec { name: "Stadium", population: 0, tiles: 0 },
+	  HouseSpec { name: "Stadium", population: 0, tiles: 0 },
+	  HouseSpec { name: "Stadium", population: 0, tiles: 0 },
+	  HouseSpec { name: "Offices", population: 140, tiles: 1 },
+	  HouseSpec { name: "Houses", population: 15, tiles: 1 },
+	  HouseSpec { name: "Houses", population: 15, tiles: 1 },
+	  HouseSpec { name: "Cinema", population: 35, tiles: 1 },
+	  HouseSpec { name: "Shopping centre", population: 180, tiles: 4 },
+	  HouseSpec { name: "Shopping centre", population: 0, tiles: 0 },
+	  HouseSpec { name: "Shopping centre", population: 0, tiles: 0 },
+	  HouseSpec { name: "Shopping centre", population: 0, tiles: 0 },
+];
@@ -0,0 +1,68 @@
+//! Openttd default industries table
+//!
+//! Indexed by the industry type. The cargos are the ids on the
+//! cargo table of the climates with the industry.
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/table/build_industry.h
+
+/// Climates where the industry can be built, one bit per climate
+pub const IC_TEMPERATE: u8 = 1 << 0;
+pub const IC_SUB_ARCTIC: u8 = 1 << 1;
+pub const IC_SUB_TROPICAL: u8 = 1 << 2;
+pub const IC_TOYLAND: u8 = 1 << 3;
+
+/// Built-in industry type
+#[derive(Debug, PartialEq)]
+pub struct IndustrySpec {
+    /// English name
+    pub name: &'static str,
+    /// Climates with the industry
+    pub climates: u8,
+    /// Produced cargo ids
+    pub produced: &'static [u8],
+    /// Accepted cargo ids
+    pub accepted: &'static [u8],
+    /// Processing industry, closed after years without deliveries
+    pub processing: bool,
+}
+
+pub static INDUSTRY_SPECS: &[IndustrySpec] = &[
+	  IndustrySpec { name: "Coal Mine", climates: IC_TEMPERATE | IC_SUB_ARCTIC, produced: &[1], accepted: &[], processing: false },
+	  IndustrySpec { name: "Power Station", climates: IC_TEMPERATE | IC_SUB_ARCTIC, produced: &[], accepted: &[1], processing: false },
+	  IndustrySpec { name: "Sawmill", climates: IC_TEMPERATE, produced: &[5], accepted: &[7], processing: true },
+	  IndustrySpec { name: "Forest", climates: IC_TEMPERATE | IC_SUB_ARCTIC, produced: &[7], accepted: &[], processing: false },
+	  IndustrySpec { name: "Oil Refinery", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL, produced: &[5], accepted: &[3], processing: true },
+	  IndustrySpec { name: "Oil Rig", climates: IC_TEMPERATE, produced: &[3, 0], accepted: &[], processing: false },
+	  IndustrySpec { name: "Factory", climates: IC_TEMPERATE, produced: &[5], accepted: &[4, 6, 9], processing: true },
+	  IndustrySpec { name: "Printing Works", climates: IC_SUB_ARCTIC, produced: &[5], accepted: &[9], processing: true },
+	  IndustrySpec { name: "Steel Mill", climates: IC_TEMPERATE, produced: &[9], accepted: &[8], processing: true },
+	  IndustrySpec { name: "Farm", climates: IC_TEMPERATE | IC_SUB_ARCTIC, produced: &[6, 4], accepted: &[], processing: false },
+	  IndustrySpec { name: "Copper Ore Mine", climates: IC_SUB_TROPICAL, produced: &[8], accepted: &[], processing: false },
+	  IndustrySpec { name: "Oil Wells", climates: IC_TEMPERATE | IC_SUB_ARCTIC | IC_SUB_TROPICAL, produced: &[3], accepted: &[], processing: false },
+	  IndustrySpec { name: "Bank", climates: IC_TEMPERATE, produced: &[10], accepted: &[10], processing: false },
+	  IndustrySpec { name: "Food Processing Plant", climates: IC_SUB_ARCTIC | IC_SUB_TROPICAL, produced: &[11], accepted: &[4, 6], processing: true },
+	  IndustrySpec { name: "Paper Mill", climates: IC_SUB_ARCTIC, produced: &[9], accepted: &[7], processing: true },
+	  IndustrySpec { name: "Gold Mine", climates: IC_SUB_ARCTIC, produced: &[10], accepted: &[], processing: false },
+	  IndustrySpec { name: "Bank", climates: IC_SUB_ARCTIC | IC_SUB_TROPICAL, produced: &[], accepted: &[10], processing: false },
+	  IndustrySpec { name: "Diamond Mine", climates: IC_SUB_TROPICAL, produced: &[10], accepted: &[], processing: false },
+	  IndustrySpec { name: "Iron Ore Mine", climates: IC_TEMPERATE, produced: &[8], accepted: &[], processing: false },
+	  IndustrySpec { name: "Fruit Plantation", climates: IC_SUB_TROPICAL, produced: &[4], accepted: &[], processing: false },
+	  IndustrySpec { name: "Rubber Plantation", climates: IC_SUB_TROPICAL, produced: &[1], accepted: &[], processing: false },
+	  IndustrySpec { name: "Water Supply", climates: IC_SUB_TROPICAL, produced: &[9], accepted: &[], processing: false },
+	  IndustrySpec { name: "Water Tower", climates: IC_SUB_TROPICAL, produced: &[], accepted: &[9], processing: false },
+	  IndustrySpec { name: "Factory", climates: IC_SUB_TROPICAL, produced: &[5], accepted: &[1, 8, 7], processing: true },
+	  IndustrySpec { name: "Farm", climates: IC_SUB_TROPICAL, produced: &[6], accepted: &[], processing: false },
+	  IndustrySpec { name: "Lumber Mill", climates: IC_SUB_TROPICAL, produced: &[7], accepted: &[], processing: false },
+	  IndustrySpec { name: "Candyfloss Forest", climates: IC_TOYLAND, produced: &[8], accepted: &[], processing: false },
+	  IndustrySpec { name: "Sweet Factory", climates: IC_TOYLAND, produced: &[5], accepted: &[6, 8, 1], processing: true },
+	  IndustrySpec { name: "Battery Farm", climates: IC_TOYLAND, produced: &[4], accepted: &[], processing: false },
+	  IndustrySpec { name: "Cola Wells", climates: IC_TOYLAND, produced: &[7], accepted: &[], processing: false },
+	  IndustrySpec { name: "Toy Shop", climates: IC_TOYLAND, produced: &[], accepted: &[3], processing: false },
+	  IndustrySpec { name: "Toy Factory", climates: IC_TOYLAND, produced: &[3], accepted: &[10, 4], processing: true },
+	  IndustrySpec { name: "Plastic Fountains", climates: IC_TOYLAND, produced: &[10], accepted: &[], processing: false },
+	  IndustrySpec { name: "Fizzy Drink Factory", climates: IC_TOYLAND, produced: &[11], accepted: &[7, 9], processing: true },
+	  IndustrySpec { name: "Bubble Generator", climates: IC_TOYLAND, produced: &[9], accepted: &[], processing: false },
+	  IndustrySpec { name: "Toffee Quarry", climates: IC_TOYLAND, produced: &[6], accepted: &[], processing: false },
+	  IndustrySpec { name: "Sugar Mine", climates: IC_TOYLAND, produced: &[1], accepted: &[], processing: false },
+];
@@ -2,3 +2,8 @@
 
 pub mod townname;
 pub mod personname;
+pub mod cargo;
+pub mod industry;
+pub mod airport;
+pub mod house;
+pub mod engine;
@@ -1,11 +1,11 @@
 //! Openttd persons names table
 
-pub static INITIAL_NAME_LETTERS: &'static[&'static str] = &[
+pub static INITIAL_NAME_LETTERS: &[&str] = &[
 	  "A", "B", "C", "D", "E", "F", "G", "H", "I", "J",
 	  "K", "L", "M", "N", "P", "R", "S", "T", "W",
 ];
 
-pub static SURNAMES: &'static[&'static str] = &[
+pub static SURNAMES: &[&str] = &[
 	  "Adams",
 	  "Allan",
 	  "Baker",
@@ -1,13 +1,13 @@
 //! Openttd town names table
 
-pub static NAME_ORIGINAL_ENGLISH_1: &'static[&'static str] = &[
+pub static NAME_ORIGINAL_ENGLISH_1: &[&str] = &[
 	  "Great ",
 	  "Little ",
 	  "New ",
 	  "Fort ",
 ];
 
-pub static NAME_ORIGINAL_ENGLISH_2: &'static[&'static str] = &[
+pub static NAME_ORIGINAL_ENGLISH_2: &[&str] = &[
 	  "Wr",
 	  "B",
 	  "C",
@@ -36,7 +36,7 @@ pub static NAME_ORIGINAL_ENGLISH_2: &'static[&'static str] = &[
 	  "W",
 ];
 
-pub static NAME_ORIGINAL_ENGLISH_3: &'static[&'static str] = &[
+pub static NAME_ORIGINAL_ENGLISH_3: &[&str] = &[
 	  "ar",
 	  "a",
 	  "e",
@@ -47,7 +47,7 @@ pub static NAME_ORIGINAL_ENGLISH_3: &'static[&'static str] = &[
 	  "en",
 ];
 
-pub static NAME_ORIGINAL_ENGLISH_4: &'static[&'static str] = &[
+pub static NAME_ORIGINAL_ENGLISH_4: &[&str] = &[
 	  "n",
 	  "ning",
 	  "ding",
@@ -57,7 +57,7 @@ pub static NAME_ORIGINAL_ENGLISH_4: &'static[&'static str] = &[
 	  "fing",
 ];
 
-pub static NAME_ORIGINAL_ENGLISH_5: &'static[&'static str] = &[
+pub static NAME_ORIGINAL_ENGLISH_5: &[&str] = &[
 	  "ville",
 	  "ham",
 	  "field",
@@ -83,7 +83,7 @@ pub static NAME_ORIGINAL_ENGLISH_5: &'static[&'static str] = &[
 	  "burg",
 ];
 
-pub static NAME_ORIGINAL_ENGLISH_6: &'static[&'static str] = &[
+pub static NAME_ORIGINAL_ENGLISH_6: &[&str] = &[
 	  "-on-sea",
 	  " Bay",
 	  " Market",
@@ -94,3 +94,2909 @@ pub static NAME_ORIGINAL_ENGLISH_6: &'static[&'static str] = &[
 	  " Ridge",
 	  " Springs",
 ];
+
+pub static NAME_ADDITIONAL_ENGLISH_PREFIX: &[&str] = &[
+	  "Great ",
+	  "Little ",
+	  "New ",
+	  "Fort ",
+	  "St. ",
+	  "Old ",
+];
+
+pub static NAME_ADDITIONAL_ENGLISH_1A: &[&str] = &[
+	  "Pen",
+	  "Lough",
+	  "Stam",
+	  "Aber",
+	  "Acc",
+	  "Ex",
+	  "Ax",
+	  "Bre",
+	  "Cum",
+	  "Dun",
+	  "Fin",
+	  "Inver",
+	  "Kin",
+	  "Mon",
+	  "Nan",
+	  "Nant",
+	  "Pit",
+	  "Pol",
+	  "Pont",
+	  "Strath",
+	  "Tre",
+	  "Tilly",
+	  "Beck",
+	  "Canter",
+	  "Bath",
+	  "Liver",
+	  "Mal",
+	  "Ox",
+	  "Bletch",
+	  "Maccles",
+	  "Grim",
+	  "Windle",
+	  "Sher",
+	  "Gates",
+	  "Orp",
+	  "Brom",
+	  "Lewis",
+	  "Whit",
+	  "White",
+	  "Worm",
+	  "Tyne",
+	  "Avon",
+	  "Stan",
+];
+
+pub static NAME_ADDITIONAL_ENGLISH_1B1: &[&str] = &[
+	  "Wr",
+	  "B",
+	  "C",
+	  "Ch",
+	  "Br",
+	  "D",
+	  "Dr",
+	  "F",
+	  "Fr",
+	  "Fl",
+	  "G",
+	  "Gr",
+	  "H",
+	  "L",
+	  "M",
+	  "N",
+	  "P",
+	  "Pr",
+	  "Pl",
+	  "R",
+	  "S",
+	  "S",
+	  "Sl",
+	  "T",
+	  "Tr",
+	  "W",
+];
+
+pub static NAME_ADDITIONAL_ENGLISH_1B2: &[&str] = &[
+	  "ar",
+	  "a",
+	  "e",
+	  "in",
+	  "on",
+	  "u",
+	  "o",
+	  "ee",
+	  "es",
+	  "ea",
+	  "un",
+	  "en",
+];
+
+pub static NAME_ADDITIONAL_ENGLISH_1B3A: &[&str] = &[
+	  "n",
+	  "d",
+	  "",
+	  "t",
+	  "",
+	  "",
+];
+
+pub static NAME_ADDITIONAL_ENGLISH_1B3B: &[&str] = &[
+	  "ning",
+	  "ding",
+	  "fing",
+];
+
+pub static NAME_ADDITIONAL_ENGLISH_2: &[&str] = &[
+	  "ville",
+	  "ham",
+	  "field",
+	  "ton",
+	  "town",
+	  "borough",
+	  "bridge",
+	  "bury",
+	  "wood",
+	  "ditch",
+	  "ford",
+	  "hall",
+	  "dean",
+	  "leigh",
+	  "dore",
+	  "ston",
+	  "stow",
+	  "church",
+	  "wich",
+	  "low",
+	  "way",
+	  "stone",
+	  "minster",
+	  "ley",
+	  "head",
+	  "bourne",
+	  "pool",
+	  "worth",
+	  "hill",
+	  "well",
+	  "hattan",
+	  "burg",
+	  "berg",
+	  "burgh",
+	  "port",
+	  "stoke",
+	  "haven",
+	  "stable",
+	  "stock",
+	  "side",
+	  "brook",
+	  "don",
+	  "den",
+	  "down",
+	  "nor",
+	  "grove",
+	  "combe",
+	  "by",
+	  "say",
+	  "ney",
+	  "chester",
+	  "dale",
+	  "ness",
+	  "shaw",
+	  "thwaite",
+];
+
+pub static NAME_ADDITIONAL_ENGLISH_3: &[&str] = &[
+	  "-on-sea",
+	  " Bay",
+	  " Market",
+	  " Beeches",
+	  " Common",
+	  " Park",
+	  " Heath",
+	  " Marsh",
+	  " Green",
+	  " Castle",
+	  " End",
+	  " Rivers",
+	  " Cross",
+	  " Bridge",
+	  " Falls",
+	  " City",
+	  " Ridge",
+	  " Springs",
+];
+
+pub static NAME_AUSTRIAN_A1: &[&str] = &[
+	  "Bad ",
+	  "Deutsch ",
+	  "Gross ",
+	  "Klein ",
+	  "Markt ",
+	  "Maria ",
+];
+
+pub static NAME_AUSTRIAN_A2: &[&str] = &[
+	  "Aus",
+	  "Alten",
+	  "Braun",
+	  "Vösl",
+	  "Mittern",
+	  "Nuss",
+	  "Neu",
+	  "Walters",
+	  "Breiten",
+	  "Eisen",
+	  "Feld",
+	  "Mittern",
+	  "Gall",
+	  "Obern",
+	  "Grat",
+	  "Heiligen",
+	  "Hof",
+	  "Holla",
+	  "Stein",
+	  "Eber",
+	  "Eggen",
+	  "Enzers",
+	  "Frauen",
+	  "Herren",
+	  "Hof",
+	  "Hütt",
+	  "Kaisers",
+	  "Königs",
+	  "Knittel",
+	  "Lang",
+	  "Ober",
+	  "Ollers",
+	  "Pfaffen",
+	  "Potten",
+	  "Salz",
+	  "Schwarz",
+	  "Stocker",
+	  "Unter",
+	  "Utten",
+	  "Vösen",
+	  "Vill",
+	  "Weissen",
+];
+
+pub static NAME_AUSTRIAN_A3: &[&str] = &[
+	  "see",
+	  "bach",
+	  "dorf",
+	  "ach",
+	  "stein",
+	  "hofen",
+	  "au",
+	  "ach",
+	  "kirch",
+	  "kirchen",
+	  "kreuz",
+	  "brunn",
+	  "siedl",
+	  "markt",
+	  "wang",
+	  "haag",
+];
+
+pub static NAME_AUSTRIAN_A4: &[&str] = &[
+	  "Bruck",
+	  "Brunn",
+	  "Gams",
+	  "Grein",
+	  "Ried",
+	  "Faak",
+	  "Zell",
+	  "Spital",
+	  "Kirchberg",
+	  "Saal",
+	  "Taferl",
+	  "Wald",
+];
+
+pub static NAME_AUSTRIAN_A5: &[&str] = &[
+	  "St. ",
+	  "Sankt ",
+];
+
+pub static NAME_AUSTRIAN_A6: &[&str] = &[
+	  "Aegyd",
+	  "Andrä",
+	  "Georgen",
+	  "Jakob",
+	  "Johann",
+	  "Leonhard",
+	  "Marein",
+	  "Lorenzen",
+	  "Margarethen",
+	  "Martin",
+	  "Michael",
+	  "Nikolai",
+	  "Oswald",
+	  "Peter",
+	  "Pölten",
+	  "Stefan",
+	  "Stephan",
+	  "Thomas",
+	  "Veit",
+	  "Wolfgang",
+];
+
+pub static NAME_AUSTRIAN_F1: &[&str] = &[
+	  " an der ",
+	  " ob der ",
+];
+
+pub static NAME_AUSTRIAN_F2: &[&str] = &[
+	  "Donau",
+	  "Steyr",
+	  "Lafnitz",
+	  "Leitha",
+	  "Thaya",
+	  "Gail",
+	  "Drau",
+	  "Salzach",
+	  "Ybbs",
+	  "Traisen",
+	  "Enns",
+	  "Mur",
+	  "Ill",
+];
+
+pub static NAME_AUSTRIAN_B1: &[&str] = &[
+	  " am ",
+];
+
+pub static NAME_AUSTRIAN_B2: &[&str] = &[
+	  "Brenner",
+	  "Dachstein",
+	  "Gebirge",
+	  "Grossglockner",
+	  "Hausruck",
+	  "Semmering",
+	  "Wagram",
+	  "Wechsel",
+	  "Wilden Kaiser",
+	  "Ziller",
+];
+
+pub static NAME_GERMAN_REAL: &[&str] = &[
+	  "Berlin",
+	  "Bonn",
+	  "Bremen",
+	  "Cottbus",
+	  "Chemnitz",
+	  "Dortmund",
+	  "Dresden",
+	  "Erfurt",
+	  "Erlangen",
+	  "Essen",
+	  "Fulda",
+	  "Gera",
+	  "Kassel",
+	  "Kiel",
+	  "Köln",
+	  "Lübeck",
+	  "Magdeburg",
+	  "München",
+	  "Potsdam",
+	  "Stuttgart",
+	  "Wiesbaden",
+];
+
+pub static NAME_GERMAN_PRE: &[&str] = &[
+	  "Bad ",
+	  "Klein ",
+	  "Neu ",
+];
+
+pub static NAME_GERMAN_1: &[&str] = &[
+	  "Alb",
+	  "Als",
+	  "Ander",
+	  "Arns",
+	  "Bruns",
+	  "Bam",
+	  "Biele",
+	  "Cloppen",
+	  "Co",
+	  "Duis",
+	  "Düssel",
+	  "Dannen",
+	  "Elb",
+	  "Els",
+	  "Elster",
+	  "Eichen",
+	  "Ems",
+	  "Fahr",
+	  "Falken",
+	  "Flens",
+	  "Frank",
+	  "Frei",
+	  "Freuden",
+	  "Fried",
+	  "Fürsten",
+	  "Hahn",
+	  "Ham",
+	  "Harz",
+	  "Heidel",
+	  "Hers",
+	  "Herz",
+	  "Holz",
+	  "Hildes",
+	  "Inns",
+	  "Ilsen",
+	  "Ingols",
+	  "Kel",
+	  "Kies",
+	  "Korn",
+	  "Kor",
+	  "Kreuz",
+	  "Kulm",
+	  "Langen",
+	  "Lim",
+	  "Lohr",
+	  "Lüne",
+	  "Mel",
+	  "Michels",
+	  "Mühl",
+	  "Naum",
+	  "Nest",
+	  "Nord",
+	  "Nort",
+	  "Nien",
+	  "Nidda",
+	  "Nieder",
+	  "Nürn",
+	  "Ober",
+	  "Offen",
+	  "Osna",
+	  "Olden",
+	  "Ols",
+	  "Oranien",
+	  "Pader",
+	  "Quedlin",
+	  "Quer",
+	  "Ravens",
+	  "Regens",
+	  "Rott",
+	  "Ros",
+	  "Rüssels",
+	  "Saal",
+	  "Saar",
+	  "Salz",
+	  "Schöne",
+	  "Schwein",
+	  "Sonder",
+	  "Sonnen",
+	  "Stein",
+	  "Strals",
+	  "Straus",
+	  "Süd",
+	  "Ton",
+	  "Unter",
+	  "Ur",
+	  "Vor",
+	  "Wald",
+	  "War",
+	  "Wert",
+	  "Wester",
+	  "Witten",
+	  "Wolfs",
+	  "Würz",
+];
+
+pub static NAME_GERMAN_2: &[&str] = &[
+	  "bach",
+	  "berg",
+	  "brück",
+	  "brücken",
+	  "burg",
+	  "dorf",
+	  "feld",
+	  "furt",
+	  "hausen",
+	  "haven",
+	  "heim",
+	  "horst",
+	  "mund",
+	  "münster",
+	  "stadt",
+	  "stedt",
+	  "stein",
+];
+
+pub static NAME_GERMAN_3_AN_DER: &[&str] = &[
+	  " an der ",
+];
+
+pub static NAME_GERMAN_3_AM: &[&str] = &[
+	  " am ",
+];
+
+pub static NAME_GERMAN_4_AN_DER: &[&str] = &[
+	  "Oder",
+	  "Spree",
+	  "Donau",
+	  "Saale",
+	  "Elbe",
+];
+
+pub static NAME_GERMAN_4_AM: &[&str] = &[
+	  "Main",
+];
+
+pub static NAME_SPANISH_REAL: &[&str] = &[
+	  "Caracas",
+	  "Maracay",
+	  "Maracaibo",
+	  "Valencia",
+	  "El Dorado",
+	  "Morrocoy",
+	  "Cata",
+	  "Cataito",
+	  "Ciudad Bolívar",
+	  "Barquisimeto",
+	  "Mérida",
+	  "Puerto Ordaz",
+	  "Santa Elena",
+	  "San Juan",
+	  "San Luis",
+	  "San Rafael",
+	  "Santiago",
+	  "Barcelona",
+	  "Barinas",
+	  "San Cristóbal",
+	  "San Francisco",
+	  "San Martín",
+	  "Guayana",
+	  "San Carlos",
+	  "El Limón",
+	  "Coro",
+	  "Corocoro",
+	  "Puerto Ayacucho",
+	  "Elorza",
+	  "Arismendi",
+	  "Trujillo",
+	  "Carupano",
+	  "Anaco",
+	  "Lima",
+	  "Cuzco",
+	  "Iquitos",
+	  "Callao",
+	  "Huacho",
+	  "Camaná",
+	  "Puerto Chala",
+	  "Santa Cruz",
+	  "Quito",
+	  "Cuenca",
+	  "Tulcán",
+	  "Esmeraldas",
+	  "Ibarra",
+	  "San Lorenzo",
+	  "Macas",
+	  "Morona",
+	  "Machala",
+	  "Zamora",
+	  "Latacunga",
+	  "Tena",
+	  "Cochabamba",
+	  "Ascensión",
+	  "Magdalena",
+	  "Santa Ana",
+	  "Manoa",
+	  "Sucre",
+	  "Oruro",
+	  "Uyuni",
+	  "Potosí",
+	  "Tupiza",
+	  "La Quiaca",
+	  "Yacuiba",
+	  "San Borja",
+	  "Fuerte Olimpo",
+	  "Fortín Esteros",
+	  "Campo Grande",
+	  "Bogotá",
+	  "El Banco",
+	  "Zaragoza",
+	  "Neiva",
+	  "Mariano",
+	  "Cali",
+	  "La Palma",
+	  "Andoas",
+	  "Barranca",
+	  "Montevideo",
+	  "Valdivia",
+	  "Arica",
+	  "Temuco",
+	  "Tocopilla",
+	  "Mendoza",
+	  "Santa Rosa",
+];
+
+pub static NAME_FRENCH_REAL: &[&str] = &[
+	  "Agincourt",
+	  "Lille",
+	  "Dinan",
+	  "Aubusson",
+	  "Rodez",
+	  "Bergerac",
+	  "Bordeaux",
+	  "Bayonne",
+	  "Montpellier",
+	  "Montélimar",
+	  "Valence",
+	  "Digne",
+	  "Nice",
+	  "Cannes",
+	  "St. Tropez",
+	  "Marseille",
+	  "Narbonne",
+	  "Sète",
+	  "Aurillac",
+	  "Gueret",
+	  "Le Creusot",
+	  "Nevers",
+	  "Auxerre",
+	  "Versailles",
+	  "Meaux",
+	  "Châlons",
+	  "Compiègne",
+	  "Metz",
+	  "Chaumont",
+	  "Langres",
+	  "Bourg",
+	  "Lyon",
+	  "Vienne",
+	  "Grenoble",
+	  "Toulon",
+	  "Rennes",
+	  "Le Mans",
+	  "Angers",
+	  "Nantes",
+	  "Châteauroux",
+	  "Orléans",
+	  "Lisieux",
+	  "Cherbourg",
+	  "Morlaix",
+	  "Cognac",
+	  "Agen",
+	  "Tulle",
+	  "Blois",
+	  "Troyes",
+	  "Charolles",
+	  "Toulouse",
+	  "Chamonix",
+	  "Tours",
+	  "Rouen",
+	  "Lourdes",
+	  "Montauban",
+	  "Pau",
+	  "Besançon",
+	  "Poitiers",
+	  "La Rochelle",
+	  "Brest",
+	  "Limoges",
+	  "Melun",
+	  "Montbard",
+	  "Dijon",
+	  "Rochefort",
+	  "Fréjus",
+	  "Briançon",
+	  "Amiens",
+	  "Le Havre",
+	  "Calais",
+];
+
+pub static NAME_SILLY_1: &[&str] = &[
+	  "Binky",
+	  "Blubber",
+	  "Bumble",
+	  "Crinkle",
+	  "Crusty",
+	  "Dangle",
+	  "Dribble",
+	  "Flippety",
+	  "Google",
+	  "Muffin",
+	  "Nosey",
+	  "Pinker",
+	  "Quack",
+	  "Rumble",
+	  "Sleepy",
+	  "Sliver",
+	  "Snoopy",
+	  "Sozzle",
+	  "Sticky",
+	  "Swiggle",
+	  "Tinker",
+	  "Toffee",
+	  "Ugly",
+	  "Weasel",
+	  "Wobble",
+	  "Wonky",
+	  "Zippy",
+	  "Fluffy",
+	  "Jelly",
+	  "Jolly",
+	  "Nutty",
+	  "Snotty",
+	  "Squishy",
+	  "Chuckle",
+	  "Giggle",
+	  "Wiggle",
+	  "Bubble",
+	  "Puddle",
+];
+
+pub static NAME_SILLY_2: &[&str] = &[
+	  "ton",
+	  "bury",
+	  "bottom",
+	  "ville",
+	  "well",
+	  "weed",
+	  "worth",
+	  "wig",
+	  "wick",
+	  "wood",
+	  "pool",
+	  "head",
+	  "burg",
+	  "gate",
+	  "bridge",
+];
+
+pub static NAME_SWEDISH_1: &[&str] = &[
+	  "Gamla ",
+	  "Lilla ",
+	  "Nya ",
+	  "Stora ",
+];
+
+pub static NAME_SWEDISH_2: &[&str] = &[
+	  "Boll",
+	  "Bor",
+	  "Ed",
+	  "En",
+	  "Erik",
+	  "Es",
+	  "Fin",
+	  "Fisk",
+	  "Grön",
+	  "Hag",
+	  "Halm",
+	  "Karl",
+	  "Kram",
+	  "Kung",
+	  "Land",
+	  "Lid",
+	  "Lin",
+	  "Mal",
+	  "Malm",
+	  "Marie",
+	  "Ner",
+	  "Norr",
+	  "Oskar",
+	  "Sand",
+	  "Skog",
+	  "Stock",
+	  "Stor",
+	  "Ström",
+	  "Sund",
+	  "Söder",
+	  "Tall",
+	  "Tratt",
+	  "Troll",
+	  "Upp",
+	  "Var",
+	  "Väster",
+	  "Ängel",
+	  "Öster",
+];
+
+pub static NAME_SWEDISH_2A: &[&str] = &[
+	  "B",
+	  "Br",
+	  "D",
+	  "Dr",
+	  "Dv",
+	  "F",
+	  "Fj",
+	  "Fl",
+	  "Fr",
+	  "G",
+	  "Gl",
+	  "Gn",
+	  "Gr",
+	  "H",
+	  "J",
+	  "K",
+	  "Kl",
+	  "Kn",
+	  "Kr",
+	  "Kv",
+	  "L",
+	  "M",
+	  "N",
+	  "P",
+	  "Pl",
+	  "Pr",
+	  "R",
+	  "S",
+	  "Sk",
+	  "Skr",
+	  "Sl",
+	  "Sn",
+	  "Sp",
+	  "Spr",
+	  "St",
+	  "Str",
+	  "Sv",
+	  "T",
+	  "Tr",
+	  "Tv",
+	  "V",
+	  "Vr",
+];
+
+pub static NAME_SWEDISH_2B: &[&str] = &[
+	  "a",
+	  "e",
+	  "i",
+	  "o",
+	  "u",
+	  "y",
+	  "å",
+	  "ä",
+	  "ö",
+];
+
+pub static NAME_SWEDISH_2C: &[&str] = &[
+	  "ck",
+	  "d",
+	  "dd",
+	  "g",
+	  "gg",
+	  "l",
+	  "ld",
+	  "m",
+	  "n",
+	  "nd",
+	  "ng",
+	  "nn",
+	  "p",
+	  "pp",
+	  "r",
+	  "rd",
+	  "rk",
+	  "rp",
+	  "rr",
+	  "rt",
+	  "s",
+	  "sk",
+	  "st",
+	  "t",
+	  "tt",
+	  "v",
+];
+
+pub static NAME_SWEDISH_3: &[&str] = &[
+	  "arp",
+	  "berg",
+	  "boda",
+	  "borg",
+	  "bro",
+	  "bukten",
+	  "by",
+	  "byn",
+	  "fors",
+	  "hammar",
+	  "hamn",
+	  "holm",
+	  "hus",
+	  "hättan",
+	  "kulle",
+	  "köping",
+	  "lund",
+	  "löv",
+	  "sala",
+	  "skrona",
+	  "slätt",
+	  "spång",
+	  "stad",
+	  "sund",
+	  "svall",
+	  "svik",
+	  "såker",
+	  "udde",
+	  "valla",
+	  "viken",
+	  "älv",
+	  "ås",
+];
+
+pub static NAME_DUTCH_1: &[&str] = &[
+	  "Nieuw ",
+	  "Oud ",
+	  "Groot ",
+	  "Zuid ",
+	  "Noord ",
+	  "Oost ",
+	  "West ",
+	  "Klein ",
+];
+
+pub static NAME_DUTCH_2: &[&str] = &[
+	  "Hoog",
+	  "Laag",
+	  "Zuider",
+	  "Zuid",
+	  "Ooster",
+	  "Oost",
+	  "Wester",
+	  "West",
+	  "Hoofd",
+	  "Midden",
+	  "Eind",
+	  "Amster",
+	  "Amstel",
+	  "Dord",
+	  "Rotter",
+	  "Haar",
+	  "Til",
+	  "Enk",
+	  "Dok",
+	  "Veen",
+	  "Leidsch",
+	  "Lely",
+	  "En",
+	  "Kaats",
+	  "U",
+	  "Maas",
+	  "Mar",
+	  "Bla",
+	  "Al",
+	  "Alk",
+	  "Eer",
+	  "Drie",
+	  "Ter",
+	  "Groes",
+	  "Goes",
+	  "Soest",
+	  "Coe",
+	  "Uit",
+	  "Zwaag",
+	  "Hellen",
+	  "Slie",
+	  "IJ",
+	  "Grubben",
+	  "Groen",
+	  "Lek",
+	  "Ridder",
+	  "Schie",
+	  "Olde",
+	  "Roose",
+	  "Loos",
+	  "Hil",
+];
+
+pub static NAME_DUTCH_3: &[&str] = &[
+	  "Drog",
+	  "Nat",
+	  "Valk",
+	  "Bob",
+	  "Dedem",
+	  "Kollum",
+	  "Best",
+	  "Hoend",
+	  "Leeuw",
+	  "Graaf",
+	  "Uithuis",
+	  "Purm",
+	  "Hard",
+	  "Hell",
+	  "Werk",
+	  "Spijk",
+	  "Vink",
+	  "Wams",
+	  "Heerhug",
+	  "Koning",
+];
+
+pub static NAME_DUTCH_4: &[&str] = &[
+	  "e",
+	  "er",
+	  "el",
+	  "en",
+	  "o",
+	  "s",
+];
+
+pub static NAME_DUTCH_5: &[&str] = &[
+	  "stad",
+	  "vorst",
+	  "dorp",
+	  "dam",
+	  "beek",
+	  "doorn",
+	  "zijl",
+	  "zijlen",
+	  "lo",
+	  "muiden",
+	  "meden",
+	  "vliet",
+	  "nisse",
+	  "daal",
+	  "vorden",
+	  "vaart",
+	  "mond",
+	  "zaal",
+	  "water",
+	  "duinen",
+	  "heuvel",
+	  "geest",
+	  "kerk",
+	  "meer",
+	  "maar",
+	  "hoorn",
+	  "rade",
+	  "wijk",
+	  "berg",
+	  "heim",
+	  "sum",
+	  "richt",
+	  "burg",
+	  "recht",
+	  "drecht",
+	  "trecht",
+	  "tricht",
+	  "dricht",
+	  "lum",
+	  "rum",
+	  "halen",
+	  "oever",
+	  "wolde",
+	  "veen",
+	  "hoven",
+	  "gast",
+	  "kum",
+	  "hage",
+	  "dijk",
+	  "zwaag",
+	  "pomp",
+	  "huizen",
+	  "bergen",
+	  "schede",
+	  "mere",
+	  "end",
+];
+
+pub static NAME_FINNISH_REAL: &[&str] = &[
+	  "Aijala",
+	  "Kisko",
+	  "Espoo",
+	  "Helsinki",
+	  "Tapiola",
+	  "Järvelä",
+	  "Lahti",
+	  "Kotka",
+	  "Hamina",
+	  "Loviisa",
+	  "Kouvola",
+	  "Tampere",
+	  "Oulu",
+	  "Salo",
+	  "Malmi",
+	  "Pelto",
+	  "Koski",
+	  "Iisalmi",
+	  "Raisio",
+	  "Taavetti",
+	  "Joensuu",
+	  "Imatra",
+	  "Tapanila",
+	  "Pasila",
+	  "Turku",
+	  "Kupittaa",
+	  "Vaasa",
+	  "Pori",
+	  "Rauma",
+	  "Kolari",
+	  "Lieksa",
+];
+
+pub static NAME_FINNISH_1: &[&str] = &[
+	  "Hiekka",
+	  "Haapa",
+	  "Mylly",
+	  "Sauna",
+	  "Uusi",
+	  "Vanha",
+	  "Kesä",
+	  "Kuusi",
+	  "Pelto",
+	  "Tuomi",
+	  "Terva",
+	  "Olki",
+	  "Heinä",
+	  "Seinä",
+	  "Rauta",
+	  "Kivi",
+	  "Lumi",
+	  "Rasi",
+];
+
+pub static NAME_FINNISH_2: &[&str] = &[
+	  "Koivu",
+	  "Ala",
+	  "Yli",
+	  "Iso",
+	  "Pikku",
+	  "Keski",
+	  "Etelä",
+	  "Pohjois",
+	  "Itä",
+	  "Länsi",
+	  "Tammi",
+	  "Mänty",
+];
+
+pub static NAME_FINNISH_3: &[&str] = &[
+	  "harju",
+	  "linna",
+	  "järvi",
+	  "kallio",
+	  "mäki",
+	  "nummi",
+	  "joki",
+	  "kylä",
+	  "lampi",
+	  "lahti",
+	  "metsä",
+	  "suo",
+	  "laakso",
+	  "niitty",
+	  "luoto",
+	  "hovi",
+	  "ranta",
+	  "koski",
+	  "salo",
+];
+
+pub static NAME_POLISH_1_M: &[&str] = &[
+	  "Wielki ",
+	  "Mały ",
+	  "Zły ",
+	  "Dobry ",
+	  "Nowy ",
+	  "Stary ",
+	  "Złoty ",
+	  "Zielony ",
+	  "Biały ",
+	  "Modry ",
+	  "Dębowy ",
+];
+
+pub static NAME_POLISH_1_F: &[&str] = &[
+	  "Wielka ",
+	  "Mała ",
+	  "Zła ",
+	  "Dobra ",
+	  "Nowa ",
+	  "Stara ",
+	  "Złota ",
+	  "Zielona ",
+	  "Biała ",
+	  "Modra ",
+	  "Dębowa ",
+];
+
+pub static NAME_POLISH_1_N: &[&str] = &[
+	  "Wielkie ",
+	  "Małe ",
+	  "Złe ",
+	  "Dobre ",
+	  "Nowe ",
+	  "Stare ",
+	  "Złote ",
+	  "Zielone ",
+	  "Białe ",
+	  "Modre ",
+	  "Dębowe ",
+];
+
+pub static NAME_POLISH_2_O: &[&str] = &[
+	  "Frombork",
+	  "Gniezno",
+	  "Olsztyn",
+	  "Toruń",
+	  "Bydgoszcz",
+	  "Terespol",
+	  "Kraków",
+	  "Poznań",
+	  "Wrocław",
+	  "Katowice",
+	  "Cieszyn",
+	  "Bytom",
+	  "Hel",
+	  "Konin",
+	  "Lublin",
+	  "Malbork",
+	  "Sopot",
+	  "Sosnowiec",
+	  "Gdańsk",
+	  "Gdynia",
+	  "Sieradz",
+	  "Sandomierz",
+	  "Szczyrk",
+	  "Szczytno",
+	  "Szczecin",
+	  "Zakopane",
+	  "Szklarska Poręba",
+	  "Bochnia",
+	  "Golub-Dobrzyń",
+	  "Chojnice",
+	  "Ostrowiec",
+	  "Otwock",
+	  "Wolsztyn",
+];
+
+pub static NAME_POLISH_2_M: &[&str] = &[
+	  "Jarocin",
+	  "Gogolin",
+	  "Tomaszów",
+	  "Piotrków",
+	  "Lidzbark",
+	  "Rypin",
+	  "Radzymin",
+	  "Wołomin",
+	  "Pruszków",
+	  "Olsztynek",
+	  "Cisek",
+	  "Krotoszyn",
+	  "Stoczek",
+	  "Lubin",
+	  "Lubicz",
+	  "Milicz",
+	  "Targ",
+	  "Ostrów",
+	  "Ozimek",
+	  "Puck",
+	  "Rzepin",
+	  "Siewierz",
+	  "Stargard",
+	  "Starogard",
+	  "Turek",
+	  "Tymbark",
+	  "Strzebielin",
+	  "Sochaczew",
+	  "Grębocin",
+	  "Gniew",
+	  "Lubliniec",
+	  "Lubasz",
+	  "Lutomiersk",
+	  "Niemodlin",
+	  "Przeworsk",
+	  "Ursus",
+	  "Tyczyn",
+	  "Sztum",
+	  "Szczebrzeszyn",
+	  "Wolin",
+	  "Wrzeszcz",
+	  "Zgierz",
+	  "Zieleniec",
+	  "Drobin",
+	  "Garwolin",
+];
+
+pub static NAME_POLISH_2_F: &[&str] = &[
+	  "Szprotawa",
+	  "Pogorzelica",
+	  "Motława",
+	  "Lubawa",
+	  "Nidzica",
+	  "Kruszwica",
+	  "Bierawa",
+	  "Brodnica",
+	  "Chojna",
+	  "Krzepica",
+	  "Ruda",
+	  "Rumia",
+	  "Tuchola",
+	  "Trzebinia",
+	  "Ustka",
+	  "Warszawa",
+	  "Bobowa",
+	  "Dukla",
+	  "Krynica",
+	  "Murowana",
+	  "Niemcza",
+	  "Zaspa",
+	  "Zawoja",
+	  "Wola",
+	  "Limanowa",
+	  "Rabka",
+	  "Skawina",
+	  "Pilawa",
+];
+
+pub static NAME_POLISH_2_N: &[&str] = &[
+	  "Lipsko",
+	  "Pilzno",
+	  "Przodkowo",
+	  "Strzelno",
+	  "Leźno",
+	  "Jasło",
+	  "Kłodzko",
+	  "Braniewo",
+	  "Chełmno",
+	  "Opole",
+	  "Zbąszynko",
+	  "Mielno",
+];
+
+pub static NAME_POLISH_3_M: &[&str] = &[
+	  " Wybudowanie",
+	  " Świętokrzyski",
+	  " Górski",
+	  " Morski",
+	  " Zdrój",
+	  " Wody",
+	  " Bajoro",
+	  " Krajeński",
+	  " Śląski",
+	  " Mazowiecki",
+	  " Pomorski",
+	  " Wielki",
+	  " Mały",
+	  " Warmiński",
+	  " Mazurski",
+	  " Mniejszy",
+	  " Większy",
+	  " Górny",
+	  " Dolny",
+	  " Stary",
+	  " Nowy",
+	  " Wielkopolski",
+	  " Wzgórze",
+	  " Mosty",
+	  " Kujawski",
+	  " Małopolski",
+	  " Podlaski",
+	  " Leśny",
+];
+
+pub static NAME_POLISH_3_F: &[&str] = &[
+	  " Wybudowanie",
+	  " Świętokrzyska",
+	  " Górska",
+	  " Morska",
+	  " Zdrój",
+	  " Woda",
+	  " Bajoro",
+	  " Krajeńska",
+	  " Śląska",
+	  " Mazowiecka",
+	  " Pomorska",
+	  " Wielka",
+	  " Mała",
+	  " Warmińska",
+	  " Mazurska",
+	  " Mniejsza",
+	  " Większa",
+	  " Górna",
+	  " Dolna",
+	  " Stara",
+	  " Nowa",
+	  " Wielkopolska",
+	  " Wzgórza",
+	  " Mosty",
+	  " Kujawska",
+	  " Małopolska",
+	  " Podlaska",
+	  " Leśna",
+];
+
+pub static NAME_POLISH_3_N: &[&str] = &[
+	  " Wybudowanie",
+	  " Świętokrzyskie",
+	  " Górskie",
+	  " Morskie",
+	  " Zdrój",
+	  " Wody",
+	  " Bajoro",
+	  " Krajeńskie",
+	  " Śląskie",
+	  " Mazowieckie",
+	  " Pomorskie",
+	  " Wielkie",
+	  " Małe",
+	  " Warmińskie",
+	  " Mazurskie",
+	  " Mniejsze",
+	  " Większe",
+	  " Górne",
+	  " Dolne",
+	  " Stare",
+	  " Nowe",
+	  " Wielkopolskie",
+	  " Wzgórze",
+	  " Mosty",
+	  " Kujawskie",
+	  " Małopolskie",
+	  " Podlaskie",
+	  " Leśne",
+];
+
+pub static NAME_CZECH_REAL: &[&str] = &[
+	  "Aš",
+	  "Benešov",
+	  "Beroun",
+	  "Blansko",
+	  "Břeclav",
+	  "Brno",
+	  "Bruntál",
+	  "Česká Lípa",
+	  "České Budějovice",
+	  "Český Krumlov",
+	  "Děčín",
+	  "Domažlice",
+	  "Dubí",
+	  "Frýdek-Místek",
+	  "Havlíčkův Brod",
+	  "Hodonín",
+	  "Hradec Králové",
+	  "Humpolec",
+	  "Cheb",
+	  "Chomutov",
+	  "Chrudim",
+	  "Jablonec nad Nisou",
+	  "Jeseník",
+	  "Jičín",
+	  "Jihlava",
+	  "Jindřichův Hradec",
+	  "Karlovy Vary",
+	  "Karviná",
+	  "Kladno",
+	  "Klatovy",
+	  "Kolín",
+	  "Kosmonosy",
+	  "Kroměříž",
+	  "Kutná Hora",
+	  "Liberec",
+	  "Litoměřice",
+	  "Louny",
+	  "Manětín",
+	  "Mělník",
+	  "Mladá Boleslav",
+	  "Most",
+	  "Náchod",
+	  "Nový Jičín",
+	  "Nymburk",
+	  "Olomouc",
+	  "Opava",
+	  "Ostrava",
+	  "Pardubice",
+	  "Pelhřimov",
+	  "Písek",
+	  "Plzeň",
+	  "Praha",
+	  "Prachatice",
+	  "Přerov",
+	  "Příbram",
+	  "Prostějov",
+	  "Rakovník",
+	  "Rokycany",
+	  "Rudná",
+	  "Rychnov nad Kněžnou",
+	  "Semily",
+	  "Sokolov",
+	  "Strakonice",
+	  "Středokluky",
+	  "Šumperk",
+	  "Svitavy",
+	  "Tábor",
+	  "Tachov",
+	  "Teplice",
+	  "Třebíč",
+	  "Trutnov",
+	  "Uherské Hradiště",
+	  "Ústí nad Labem",
+	  "Ústí nad Orlicí",
+	  "Vsetín",
+	  "Vyškov",
+	  "Žďár nad Sázavou",
+	  "Zlín",
+	  "Znojmo",
+];
+
+pub static NAME_CZECH_SUBST_POSTFIX: &[&str] = &[
+	  "av",
+	  "an",
+	  "at",
+	  "ov",
+	  "on",
+	  "ot",
+	  "ev",
+	  "ec",
+	  "en",
+	  "et",
+	  "es",
+	  "er",
+];
+
+pub static NAME_CZECH_SUFFIX: &[&str] = &[
+	  "nad Cidlinou",
+	  "nad Dyjí",
+	  "nad Jihlavou",
+	  "nad Labem",
+	  "nad Lesy",
+	  "nad Moravou",
+	  "nad Nisou",
+	  "nad Odrou",
+	  "nad Ostravicí",
+	  "nad Sázavou",
+	  "nad Vltavou",
+	  "pod Pradědem",
+	  "pod Radhoštěm",
+	  "pod Řípem",
+	  "pod Sněžkou",
+	  "pod Špičákem",
+	  "pod Sedlem",
+	  "v Čechách",
+	  "na Moravě",
+];
+
+/// Grammatical gender of the czech names
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CzechGender {
+	  /// Singular masculine
+	  SMasc,
+	  /// Singular feminine
+	  SFem,
+	  /// Singular neuter
+	  SNeut,
+	  /// Plural masculine
+	  PMasc,
+	  /// Plural feminine
+	  PFem,
+	  /// Plural neuter
+	  PNeut,
+	  /// Any gender, defined by the ending
+	  Free,
+	  /// Any gender but neuter, defined by the ending
+	  NFree,
+}
+
+/// Declension pattern of the czech adjectives
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CzechPattern {
+	  Jarni,
+	  Mlady,
+	  Privl,
+}
+
+/// Czech name parts allowed after the colour adjectives
+pub const CZC_COLOR: u8 = 1;
+/// Czech stems that need a postfix
+pub const CZC_POSTFIX: u8 = 2;
+/// Czech stems that can't have a postfix
+pub const CZC_NOPOSTFIX: u8 = 4;
+pub const CZC_ANY: u8 = CZC_COLOR | CZC_POSTFIX | CZC_NOPOSTFIX;
+
+/// Length of the czech stems and endings
+pub const CZA_SHORT: u8 = 1;
+pub const CZA_MIDDLE: u8 = 2;
+pub const CZA_LONG: u8 = 4;
+pub const CZA_ALL: u8 = CZA_SHORT | CZA_MIDDLE | CZA_LONG;
+
+/// Czech substantive, full or only the stem/ending
+pub struct CzechNameSubst {
+	  pub gender: CzechGender,
+	  pub allow: u8,
+	  pub choose: u8,
+	  pub name: &'static str,
+}
+
+/// Czech adjective, on the masculine form
+pub struct CzechNameAdj {
+	  pub pattern: CzechPattern,
+	  pub choose: u8,
+	  pub name: &'static str,
+}
+
+/// Last letter of the adjectives, by the gender and pattern
+///
+/// The masculine possessive ones (-ovX) are replaced by -uv.
+pub static NAME_CZECH_PATMOD: [[&str; 3]; 6] = [
+	  /* SMasc */ ["í", "ý", "X"],
+	  /* SFem */  ["í", "á", "a"],
+	  /* SNeut */ ["í", "é", "o"],
+	  /* PMasc */ ["í", "é", "y"],
+	  /* PFem */  ["í", "é", "y"],
+	  /* PNeut */ ["í", "á", "a"],
+];
+
+pub static NAME_CZECH_ADJ: &[CzechNameAdj] = &[
+	  CzechNameAdj { pattern: CzechPattern::Jarni, choose: CZC_ANY, name: "Horní" },
+	  CzechNameAdj { pattern: CzechPattern::Jarni, choose: CZC_ANY, name: "Dolní" },
+	  CzechNameAdj { pattern: CzechPattern::Jarni, choose: CZC_ANY, name: "Přední" },
+	  CzechNameAdj { pattern: CzechPattern::Jarni, choose: CZC_ANY, name: "Zadní" },
+	  CzechNameAdj { pattern: CzechPattern::Jarni, choose: CZC_ANY, name: "Kostelní" },
+	  CzechNameAdj { pattern: CzechPattern::Jarni, choose: CZC_ANY, name: "Havraní" },
+	  CzechNameAdj { pattern: CzechPattern::Jarni, choose: CZC_ANY, name: "Říční" },
+	  CzechNameAdj { pattern: CzechPattern::Jarni, choose: CZC_ANY, name: "Jezerní" },
+	  CzechNameAdj { pattern: CzechPattern::Mlady, choose: CZC_ANY, name: "Velký" },
+	  CzechNameAdj { pattern: CzechPattern::Mlady, choose: CZC_ANY, name: "Malý" },
+	  CzechNameAdj { pattern: CzechPattern::Mlady, choose: CZC_ANY, name: "Vysoký" },
+	  CzechNameAdj { pattern: CzechPattern::Mlady, choose: CZC_ANY, name: "Český" },
+	  CzechNameAdj { pattern: CzechPattern::Mlady, choose: CZC_ANY, name: "Moravský" },
+	  CzechNameAdj { pattern: CzechPattern::Mlady, choose: CZC_ANY, name: "Slovácký" },
+	  CzechNameAdj { pattern: CzechPattern::Mlady, choose: CZC_ANY, name: "Slezský" },
+	  CzechNameAdj { pattern: CzechPattern::Mlady, choose: CZC_ANY, name: "Uherský" },
+	  CzechNameAdj { pattern: CzechPattern::Mlady, choose: CZC_ANY, name: "Starý" },
+	  CzechNameAdj { pattern: CzechPattern::Mlady, choose: CZC_ANY, name: "Nový" },
+	  CzechNameAdj { pattern: CzechPattern::Mlady, choose: CZC_ANY, name: "Mladý" },
+	  CzechNameAdj { pattern: CzechPattern::Mlady, choose: CZC_ANY, name: "Krásný" },
+	  CzechNameAdj { pattern: CzechPattern::Mlady, choose: CZC_ANY, name: "Kamenný" },
+	  CzechNameAdj { pattern: CzechPattern::Mlady, choose: CZC_COLOR, name: "Černý" },
+	  CzechNameAdj { pattern: CzechPattern::Mlady, choose: CZC_COLOR, name: "Bílý" },
+	  CzechNameAdj { pattern: CzechPattern::Mlady, choose: CZC_COLOR, name: "Zelený" },
+	  CzechNameAdj { pattern: CzechPattern::Mlady, choose: CZC_COLOR, name: "Modrý" },
+	  CzechNameAdj { pattern: CzechPattern::Mlady, choose: CZC_COLOR, name: "Červený" },
+	  CzechNameAdj { pattern: CzechPattern::Privl, choose: CZC_ANY, name: "Kaplanova" },
+	  CzechNameAdj { pattern: CzechPattern::Privl, choose: CZC_ANY, name: "Karlova" },
+	  CzechNameAdj { pattern: CzechPattern::Privl, choose: CZC_ANY, name: "Petrova" },
+	  CzechNameAdj { pattern: CzechPattern::Privl, choose: CZC_ANY, name: "Pavlova" },
+];
+
+pub static NAME_CZECH_SUBST_FULL: &[CzechNameSubst] = &[
+	  CzechNameSubst { gender: CzechGender::SMasc, allow: CZA_ALL, choose: CZC_COLOR, name: "Sedlec" },
+	  CzechNameSubst { gender: CzechGender::SMasc, allow: CZA_ALL, choose: CZC_COLOR, name: "Brod" },
+	  CzechNameSubst { gender: CzechGender::SMasc, allow: CZA_ALL, choose: CZC_COLOR, name: "Brodek" },
+	  CzechNameSubst { gender: CzechGender::SMasc, allow: CZA_ALL, choose: CZC_COLOR, name: "Hrádek" },
+	  CzechNameSubst { gender: CzechGender::SMasc, allow: CZA_ALL, choose: CZC_COLOR, name: "Újezd" },
+	  CzechNameSubst { gender: CzechGender::SMasc, allow: CZA_ALL, choose: CZC_COLOR, name: "Dvůr" },
+	  CzechNameSubst { gender: CzechGender::SMasc, allow: CZA_ALL, choose: CZC_COLOR, name: "Žďár" },
+	  CzechNameSubst { gender: CzechGender::SMasc, allow: CZA_ALL, choose: 0, name: "Kostelec" },
+	  CzechNameSubst { gender: CzechGender::SMasc, allow: CZA_ALL, choose: 0, name: "Týnec" },
+	  CzechNameSubst { gender: CzechGender::SFem, allow: CZA_ALL, choose: CZC_COLOR, name: "Lhota" },
+	  CzechNameSubst { gender: CzechGender::SFem, allow: CZA_ALL, choose: CZC_COLOR, name: "Ves" },
+	  CzechNameSubst { gender: CzechGender::SFem, allow: CZA_ALL, choose: CZC_COLOR, name: "Hora" },
+	  CzechNameSubst { gender: CzechGender::SFem, allow: CZA_ALL, choose: CZC_COLOR, name: "Lípa" },
+	  CzechNameSubst { gender: CzechGender::SFem, allow: CZA_ALL, choose: CZC_COLOR, name: "Voda" },
+	  CzechNameSubst { gender: CzechGender::SNeut, allow: CZA_ALL, choose: CZC_COLOR, name: "Pole" },
+	  CzechNameSubst { gender: CzechGender::SNeut, allow: CZA_ALL, choose: 0, name: "Město" },
+	  CzechNameSubst { gender: CzechGender::SNeut, allow: CZA_ALL, choose: CZC_COLOR, name: "Údolí" },
+	  CzechNameSubst { gender: CzechGender::PMasc, allow: CZA_ALL, choose: CZC_COLOR, name: "Dvory" },
+	  CzechNameSubst { gender: CzechGender::PFem, allow: CZA_ALL, choose: CZC_COLOR, name: "Lhoty" },
+	  CzechNameSubst { gender: CzechGender::PFem, allow: CZA_ALL, choose: CZC_COLOR, name: "Hory" },
+];
+
+pub static NAME_CZECH_SUBST_STEM: &[CzechNameSubst] = &[
+	  CzechNameSubst { gender: CzechGender::SMasc, allow: CZA_MIDDLE, choose: CZC_COLOR, name: "Kostel" },
+	  CzechNameSubst { gender: CzechGender::SMasc, allow: CZA_MIDDLE, choose: CZC_COLOR, name: "Klášter" },
+	  CzechNameSubst { gender: CzechGender::SMasc, allow: CZA_SHORT, choose: CZC_COLOR, name: "Lhot" },
+	  CzechNameSubst { gender: CzechGender::SFem, allow: CZA_SHORT, choose: CZC_COLOR, name: "Lhot" },
+	  CzechNameSubst { gender: CzechGender::SFem, allow: CZA_SHORT, choose: CZC_COLOR, name: "Hur" },
+	  CzechNameSubst { gender: CzechGender::Free, allow: CZA_MIDDLE | CZA_LONG, choose: 0, name: "Sedl" },
+	  CzechNameSubst { gender: CzechGender::Free, allow: CZA_ALL, choose: CZC_COLOR, name: "Hrad" },
+	  CzechNameSubst { gender: CzechGender::NFree, allow: CZA_MIDDLE, choose: 0, name: "Pras" },
+	  CzechNameSubst { gender: CzechGender::NFree, allow: CZA_MIDDLE, choose: 0, name: "Baž" },
+	  CzechNameSubst { gender: CzechGender::NFree, allow: CZA_MIDDLE, choose: 0, name: "Tes" },
+	  CzechNameSubst { gender: CzechGender::NFree, allow: CZA_MIDDLE, choose: 0, name: "Uh" },
+	  CzechNameSubst { gender: CzechGender::NFree, allow: CZA_MIDDLE | CZA_LONG, choose: 0, name: "Hořeh" },
+	  CzechNameSubst { gender: CzechGender::NFree, allow: CZA_MIDDLE | CZA_LONG, choose: 0, name: "Brod" },
+	  CzechNameSubst { gender: CzechGender::Free, allow: CZA_ALL, choose: 0, name: "Bystř" },
+	  CzechNameSubst { gender: CzechGender::Free, allow: CZA_ALL, choose: CZC_NOPOSTFIX, name: "Mokr" },
+	  CzechNameSubst { gender: CzechGender::Free, allow: CZA_ALL, choose: CZC_NOPOSTFIX, name: "Vys" },
+	  CzechNameSubst { gender: CzechGender::Free, allow: CZA_ALL, choose: CZC_POSTFIX, name: "Dub" },
+	  CzechNameSubst { gender: CzechGender::Free, allow: CZA_ALL, choose: CZC_POSTFIX, name: "Lip" },
+	  CzechNameSubst { gender: CzechGender::Free, allow: CZA_ALL, choose: 0, name: "Most" },
+	  CzechNameSubst { gender: CzechGender::Free, allow: CZA_ALL, choose: 0, name: "Vod" },
+	  CzechNameSubst { gender: CzechGender::Free, allow: CZA_ALL, choose: 0, name: "Kam" },
+];
+
+/// Endings of the stems, grouped by the gender
+pub static NAME_CZECH_SUBST_ENDING: &[CzechNameSubst] = &[
+	  CzechNameSubst { gender: CzechGender::SMasc, allow: CZA_SHORT | CZA_MIDDLE, choose: CZC_ANY, name: "ec" },
+	  CzechNameSubst { gender: CzechGender::SMasc, allow: CZA_SHORT | CZA_MIDDLE, choose: CZC_ANY, name: "ín" },
+	  CzechNameSubst { gender: CzechGender::SMasc, allow: CZA_ALL, choose: CZC_ANY, name: "ov" },
+	  CzechNameSubst { gender: CzechGender::SMasc, allow: CZA_SHORT | CZA_LONG, choose: CZC_ANY, name: "kov" },
+	  CzechNameSubst { gender: CzechGender::SMasc, allow: CZA_LONG, choose: CZC_POSTFIX, name: "ník" },
+	  CzechNameSubst { gender: CzechGender::SMasc, allow: CZA_LONG, choose: CZC_ANY, name: "burk" },
+	  CzechNameSubst { gender: CzechGender::SFem, allow: CZA_SHORT, choose: CZC_ANY, name: "ka" },
+	  CzechNameSubst { gender: CzechGender::SFem, allow: CZA_MIDDLE, choose: CZC_ANY, name: "inka" },
+	  CzechNameSubst { gender: CzechGender::SFem, allow: CZA_MIDDLE, choose: CZC_ANY, name: "ná" },
+	  CzechNameSubst { gender: CzechGender::SFem, allow: CZA_ALL, choose: CZC_ANY, name: "ava" },
+	  CzechNameSubst { gender: CzechGender::SFem, allow: CZA_LONG, choose: CZC_POSTFIX, name: "ína" },
+	  CzechNameSubst { gender: CzechGender::SFem, allow: CZA_LONG, choose: CZC_ANY, name: "ovka" },
+	  CzechNameSubst { gender: CzechGender::SNeut, allow: CZA_SHORT, choose: CZC_ANY, name: "ko" },
+	  CzechNameSubst { gender: CzechGender::SNeut, allow: CZA_ALL, choose: CZC_ANY, name: "ovo" },
+	  CzechNameSubst { gender: CzechGender::SNeut, allow: CZA_LONG, choose: CZC_ANY, name: "ovsko" },
+	  CzechNameSubst { gender: CzechGender::PMasc, allow: CZA_ALL, choose: CZC_ANY, name: "ovice" },
+	  CzechNameSubst { gender: CzechGender::PMasc, allow: CZA_MIDDLE, choose: CZC_ANY, name: "any" },
+	  CzechNameSubst { gender: CzechGender::PFem, allow: CZA_ALL, choose: CZC_ANY, name: "ice" },
+	  CzechNameSubst { gender: CzechGender::PFem, allow: CZA_MIDDLE, choose: CZC_ANY, name: "iny" },
+	  CzechNameSubst { gender: CzechGender::PNeut, allow: CZA_ALL, choose: CZC_ANY, name: "a" },
+	  CzechNameSubst { gender: CzechGender::PNeut, allow: CZA_SHORT, choose: CZC_ANY, name: "ka" },
+];
+
+
+pub static NAME_ROMANIAN_REAL: &[&str] = &[
+	  "Adjud",
+	  "Alba Iulia",
+	  "Alexandria",
+	  "Babadag",
+	  "Bacău",
+	  "Baia Mare",
+	  "Băile Herculane",
+	  "Băilesti",
+	  "Bârlad",
+	  "Bicaz",
+	  "Bistriţa",
+	  "Blaj",
+	  "Borsec",
+	  "Botoşani",
+	  "Brăila",
+	  "Braşov",
+	  "Bucureşti",
+	  "Buftea",
+	  "Buzău",
+	  "Călăraşi",
+	  "Caransebeş",
+	  "Cernavodă",
+	  "Cluj-Napoca",
+	  "Constanţa",
+	  "Covasna",
+	  "Craiova",
+	  "Dej",
+	  "Deva",
+	  "Dorohoi",
+	  "Dr.-Tr. Severin",
+	  "Drăgăşani",
+	  "Făgăraş",
+	  "Făurei",
+	  "Feteşti",
+	  "Focşani",
+	  "Galaţi",
+	  "Giurgiu",
+	  "Hârşova",
+	  "Hunedoara",
+	  "Huşi",
+	  "Iaşi",
+	  "Isaccea",
+	  "Lugoj",
+	  "Măcin",
+	  "Mangalia",
+	  "Medgidia",
+	  "Mediaş",
+	  "Miercurea Ciuc",
+	  "Mizil",
+	  "Motru",
+	  "Năsăud",
+	  "Năvodari",
+	  "Odobeşti",
+	  "Olteniţa",
+	  "Oneşti",
+	  "Oradea",
+	  "Orşova",
+	  "Petroşani",
+	  "Piatra Neamţ",
+	  "Piteşti",
+	  "Ploieşti",
+	  "Predeal",
+	  "Râmnicu Vâlcea",
+	  "Reghin",
+	  "Reşiţa",
+	  "Roman",
+	  "Roşiorii de Vede",
+	  "Satu Mare",
+	  "Sebeş",
+	  "Sfântu Gheorghe",
+	  "Sibiu",
+	  "Sighişoara",
+	  "Sinaia",
+	  "Slatina",
+	  "Slobozia",
+	  "Sovata",
+	  "Suceava",
+	  "Sulina",
+	  "Ţăndărei",
+	  "Târgovişte",
+	  "Târgu Jiu",
+	  "Târgu Mureş",
+	  "Tecuci",
+	  "Timişoara",
+	  "Tulcea",
+	  "Turda",
+	  "Turnu Măgurele",
+	  "Urziceni",
+	  "Vaslui",
+	  "Vatra Dornei",
+	  "Victoria",
+	  "Videle",
+	  "Zalău",
+];
+
+pub static NAME_SLOVAK_REAL: &[&str] = &[
+	  "Bratislava",
+	  "Banovce nad Bebravou",
+	  "Banska Bystrica",
+	  "Banska Stiavnica",
+	  "Bardejov",
+	  "Brezno",
+	  "Brezova pod Bradlom",
+	  "Bytca",
+	  "Cadca",
+	  "Cierna nad Tisou",
+	  "Detva",
+	  "Dolny Kubin",
+	  "Dunajska Streda",
+	  "Gabcikovo",
+	  "Galanta",
+	  "Gbely",
+	  "Gelnica",
+	  "Handlova",
+	  "Hlohovec",
+	  "Holic",
+	  "Humenne",
+	  "Hurbanovo",
+	  "Kezmarok",
+	  "Komarno",
+	  "Kosice",
+	  "Kremnica",
+	  "Krompachy",
+	  "Kuty",
+	  "Leopoldov",
+	  "Levoca",
+	  "Liptovsky Mikulas",
+	  "Lucenec",
+	  "Malacky",
+	  "Martin",
+	  "Medzilaborce",
+	  "Michalovce",
+	  "Modra",
+	  "Myjava",
+	  "Namestovo",
+	  "Nitra",
+	  "Nova Bana",
+	  "Nove Mesto nad Vahom",
+	  "Nove Zamky",
+	  "Partizanske",
+	  "Pezinok",
+	  "Piestany",
+	  "Poltar",
+	  "Poprad",
+	  "Povazska Bystrica",
+	  "Prievidza",
+	  "Puchov",
+	  "Revuca",
+	  "Rimavska Sobota",
+	  "Roznava",
+	  "Ruzomberok",
+	  "Sabinov",
+	  "Sala",
+	  "Senec",
+	  "Senica",
+	  "Sered",
+	  "Skalica",
+	  "Sladkovicovo",
+	  "Smolenice",
+	  "Snina",
+	  "Stara Lubovna",
+	  "Stara Tura",
+	  "Strazske",
+	  "Stropkov",
+	  "Stupava",
+	  "Sturovo",
+	  "Sulekovo",
+	  "Topolcany",
+	  "Trebisov",
+	  "Trencin",
+	  "Trnava",
+	  "Turcianske Teplice",
+	  "Tvrdosin",
+	  "Vrable",
+	  "Vranov nad Toplovou",
+	  "Zahorska Bystrica",
+	  "Zdiar",
+	  "Ziar nad Hronom",
+	  "Zilina",
+	  "Zlate Moravce",
+	  "Zvolen",
+];
+
+pub static NAME_NORWEGIAN_1: &[&str] = &[
+	  "Arna",
+	  "Aust",
+	  "Bjørk",
+	  "Bjørn",
+	  "Brand",
+	  "Bø",
+	  "Eid",
+	  "Fjell",
+	  "Flat",
+	  "Fred",
+	  "Gran",
+	  "Grøn",
+	  "Hag",
+	  "Hamar",
+	  "Hol",
+	  "Hov",
+	  "Kongs",
+	  "Kvam",
+	  "Lang",
+	  "Lille",
+	  "Lys",
+	  "Mo",
+	  "Nord",
+	  "Ny",
+	  "Rød",
+	  "Sand",
+	  "Skog",
+	  "Sol",
+	  "Stor",
+	  "Sunn",
+	  "Sør",
+	  "Tind",
+	  "Vang",
+	  "Vest",
+	  "Øst",
+];
+
+pub static NAME_NORWEGIAN_2: &[&str] = &[
+	  "bakken",
+	  "berg",
+	  "bo",
+	  "bu",
+	  "by",
+	  "dal",
+	  "en",
+	  "fjell",
+	  "foss",
+	  "hamn",
+	  "havn",
+	  "heim",
+	  "holm",
+	  "jord",
+	  "land",
+	  "lia",
+	  "lo",
+	  "lund",
+	  "mo",
+	  "mork",
+	  "nes",
+	  "rud",
+	  "sand",
+	  "set",
+	  "sjø",
+	  "stad",
+	  "strand",
+	  "sund",
+	  "svik",
+	  "tun",
+	  "vik",
+	  "voll",
+	  "øy",
+	  "ås",
+];
+
+pub static NAME_NORWEGIAN_REAL: &[&str] = &[
+	  "Alta",
+	  "Arendal",
+	  "Askim",
+	  "Bergen",
+	  "Bodø",
+	  "Brevik",
+	  "Bryne",
+	  "Brønnøysund",
+	  "Drammen",
+	  "Drøbak",
+	  "Egersund",
+	  "Elverum",
+	  "Farsund",
+	  "Fauske",
+	  "Finnsnes",
+	  "Flekkefjord",
+	  "Flora",
+	  "Fosnavåg",
+	  "Fredrikstad",
+	  "Gjøvik",
+	  "Grimstad",
+	  "Halden",
+	  "Hamar",
+	  "Hammerfest",
+	  "Harstad",
+	  "Haugesund",
+	  "Holmestrand",
+	  "Horten",
+	  "Jessheim",
+	  "Kongsberg",
+	  "Kongsvinger",
+	  "Kopervik",
+	  "Kragerø",
+	  "Kristiansand",
+	  "Kristiansund",
+	  "Langesund",
+	  "Larvik",
+	  "Leknes",
+	  "Levanger",
+	  "Lillehammer",
+	  "Lillesand",
+	  "Lillestrøm",
+	  "Mandal",
+	  "Mo i Rana",
+	  "Molde",
+	  "Mosjøen",
+	  "Moss",
+	  "Mysen",
+	  "Namsos",
+	  "Narvik",
+	  "Notodden",
+	  "Odda",
+	  "Orkanger",
+	  "Oslo",
+	  "Porsgrunn",
+	  "Risør",
+	  "Rørvik",
+	  "Sandefjord",
+	  "Sandnes",
+	  "Sandnessjøen",
+	  "Sarpsborg",
+	  "Skien",
+	  "Stavanger",
+	  "Steinkjer",
+	  "Stjørdalshalsen",
+	  "Stord",
+	  "Svolvær",
+	  "Tromsø",
+	  "Trondheim",
+	  "Tvedestrand",
+	  "Tønsberg",
+	  "Vadsø",
+	  "Vardø",
+	  "Vinstra",
+	  "Ålesund",
+	  "Åndalsnes",
+];
+
+pub static NAME_HUNGARIAN_REAL: &[&str] = &[
+	  "Budapest",
+	  "Debrecen",
+	  "Győr",
+	  "Miskolc",
+	  "Nyíregyháza",
+	  "Pécs",
+	  "Szeged",
+	  "Szombathely",
+	  "Székesfehérvár",
+	  "Veszprém",
+	  "Zalaegerszeg",
+	  "Kecskemét",
+	  "Kaposvár",
+	  "Eger",
+	  "Békéscsaba",
+	  "Szolnok",
+	  "Tatabánya",
+	  "Salgótarján",
+	  "Sopron",
+	  "Szekszárd",
+	  "Hódmezővásárhely",
+	  "Dunaújváros",
+	  "Nagykanizsa",
+	  "Érd",
+	  "Baja",
+	  "Vác",
+	  "Gödöllő",
+	  "Ózd",
+	  "Cegléd",
+	  "Siófok",
+];
+
+pub static NAME_HUNGARIAN_1: &[&str] = &[
+	  "Nagy",
+	  "Kis",
+	  "Felső",
+	  "Alsó",
+	  "Új",
+];
+
+pub static NAME_HUNGARIAN_2: &[&str] = &[
+	  "Bodrog",
+	  "Dráva",
+	  "Duna",
+	  "Hejő",
+	  "Hernád",
+	  "Rába",
+	  "Sajó",
+	  "Szamos",
+	  "Tisza",
+	  "Zala",
+	  "Balaton",
+	  "Fertő",
+	  "Bakony",
+	  "Cserhát",
+	  "Bihar",
+	  "Hajdú",
+	  "Jász",
+	  "Kun",
+	  "Magyar",
+	  "Nógrád",
+	  "Nyír",
+	  "Somogy",
+	  "Székely",
+	  "Buda",
+	  "Győr",
+	  "Pest",
+	  "Fehér",
+	  "Cserép",
+	  "Erdő",
+	  "Hegy",
+	  "Homok",
+	  "Mező",
+	  "Puszta",
+	  "Sár",
+	  "Császár",
+	  "Herceg",
+	  "Király",
+	  "Nemes",
+	  "Püspök",
+	  "Szent",
+	  "Almás",
+	  "Szilvás",
+	  "Agg",
+	  "Aranyos",
+	  "Békés",
+	  "Egyházas",
+	  "Gagy",
+	  "Heves",
+	  "Kapos",
+	  "Tápió",
+	  "Torna",
+	  "Vas",
+	  "Vámos",
+	  "Vásáros",
+];
+
+pub static NAME_HUNGARIAN_3: &[&str] = &[
+	  "apáti",
+	  "bába",
+	  "bikk",
+	  "dob",
+	  "fa",
+	  "föld",
+	  "hegyes",
+	  "kak",
+	  "kereszt",
+	  "kürt",
+	  "ladány",
+	  "mérges",
+	  "szalonta",
+	  "telek",
+	  "vas",
+	  "völgy",
+];
+
+pub static NAME_HUNGARIAN_4: &[&str] = &[
+	  "alja",
+	  "egyháza",
+	  "háza",
+	  "úr",
+	  "vár",
+];
+
+pub static NAME_SWISS_REAL: &[&str] = &[
+	  "Aarau",
+	  "Aesch",
+	  "Altdorf",
+	  "Arosa",
+	  "Appenzell",
+	  "Arbon",
+	  "Altstätten",
+	  "Baar",
+	  "Baden",
+	  "Bellinzona",
+	  "Brig-Glis",
+	  "Bienne",
+	  "Burgdorf",
+	  "Bern",
+	  "Basel",
+	  "Bülach",
+	  "Carouge",
+	  "Cham",
+	  "Chiasso",
+	  "Chur",
+	  "Davos",
+	  "Delsberg",
+	  "Dübendorf",
+	  "Emmen",
+	  "Engelberg",
+	  "Fribourg",
+	  "Frauenfeld",
+	  "Genève",
+	  "Glarus",
+	  "Grenchen",
+	  "Gstaad",
+	  "Herisau",
+	  "Horgen",
+	  "Interlaken",
+	  "Kloten",
+	  "Kreuzlingen",
+	  "Küssnacht",
+	  "La Chaux-de-Fonds",
+	  "Lausanne",
+	  "Liestal",
+	  "Locarno",
+	  "Lugano",
+	  "Luzern",
+	  "Martigny",
+	  "Montreux",
+	  "Neuchâtel",
+	  "Olten",
+	  "Pfäffikon",
+	  "Rapperswil",
+	  "Riehen",
+	  "Schaffhausen",
+	  "Schwyz",
+	  "Sion",
+	  "Solothurn",
+	  "St. Gallen",
+	  "St. Moritz",
+	  "Stans",
+	  "Thun",
+	  "Uster",
+	  "Vevey",
+	  "Wädenswil",
+	  "Wetzikon",
+	  "Winterthur",
+	  "Yverdon-les-Bains",
+	  "Zermatt",
+	  "Zofingen",
+	  "Zug",
+	  "Zürich",
+];
+
+pub static NAME_DANISH_1: &[&str] = &[
+	  "Gamle ",
+	  "Lille ",
+	  "Nye ",
+	  "Store ",
+	  "Kirke ",
+	  "Nørre ",
+	  "Vester ",
+	  "Sønder ",
+	  "Øster ",
+	  "Hvide ",
+	  "Høje ",
+	  "Kongens ",
+];
+
+pub static NAME_DANISH_2: &[&str] = &[
+	  "Ager",
+	  "Alle",
+	  "Aske",
+	  "Balle",
+	  "Bede",
+	  "Birke",
+	  "Bjerring",
+	  "Bjæver",
+	  "Blommens",
+	  "Blok",
+	  "Bolder",
+	  "Bred",
+	  "Charlotten",
+	  "Christians",
+	  "Danne",
+	  "Diana",
+	  "Es",
+	  "Fredens",
+	  "Frederiks",
+	  "Fugle",
+	  "Fåre",
+	  "Gille",
+	  "Gis",
+	  "Givs",
+	  "Glams",
+	  "Glo",
+	  "Guld",
+	  "Had",
+	  "Haralds",
+	  "Hassel",
+	  "Hede",
+	  "Helle",
+	  "Hessel",
+	  "Hjorts",
+	  "Hols",
+	  "Horn",
+	  "Humle",
+	  "Høj",
+	  "Hør",
+	  "Is",
+	  "Jyde",
+	  "Jægers",
+	  "Karls",
+	  "Klov",
+	  "Kokke",
+	  "Kvist",
+	  "Lang",
+	  "Lange",
+	  "Mari",
+	  "Nord",
+	  "Ny",
+	  "Oks",
+	  "Ring",
+	  "Rød",
+	  "Rung",
+	  "Rør",
+	  "Rønne",
+	  "Rønnen",
+	  "Rå",
+	  "Sam",
+	  "Sand",
+	  "Sill",
+	  "Skall",
+	  "Skov",
+	  "Skånings",
+	  "Sol",
+	  "Sorte",
+	  "Sydhavns",
+	  "Sæl",
+	  "Tyre",
+	  "Ulster",
+	  "Valby",
+	  "Vejle",
+	  "Vem",
+	  "Vester",
+	  "Vig",
+	  "Viller",
+	  "Vor",
+];
+
+pub static NAME_DANISH_3: &[&str] = &[
+	  "anger",
+	  "balle",
+	  "bjerg",
+	  "borg",
+	  "bro",
+	  "bæk",
+	  "dal",
+	  "drup",
+	  "fred",
+	  "gård",
+	  "have",
+	  "havn",
+	  "holm",
+	  "hus",
+	  "høj",
+	  "købing",
+	  "lund",
+	  "lunde",
+	  "mark",
+	  "rup",
+	  "rød",
+	  "sted",
+	  "strup",
+	  "sø",
+	  "toft",
+	  "ved",
+	  "vig",
+	  "bøl",
+	  "torp",
+];
+
+pub static NAME_TURKISH_PREFIX: &[&str] = &[
+	  "Akça",
+	  "Altın",
+	  "Bahçe",
+	  "Boz",
+	  "Büyük",
+	  "Çay",
+	  "Doğu",
+	  "Eski",
+	  "Güzel",
+	  "Kızıl",
+	  "Küçük",
+	  "Orta",
+	  "Sarı",
+	  "Sultan",
+	  "Ulu",
+	  "Yeni",
+];
+
+pub static NAME_TURKISH_MIDDLE: &[&str] = &[
+	  "agaç",
+	  "ayva",
+	  "çam",
+	  "elma",
+	  "kurt",
+	  "pazar",
+	  "yalı",
+];
+
+pub static NAME_TURKISH_SUFFIX: &[&str] = &[
+	  "dere",
+	  "hüyük",
+	  "ırmak",
+	  "köy",
+	  "lar",
+	  "orman",
+	  "pınar",
+	  "tepe",
+	  "yazı",
+	  "yurt",
+];
+
+pub static NAME_TURKISH_REAL: &[&str] = &[
+	  "Adana",
+	  "Adıyaman",
+	  "Afyon",
+	  "Ağrı",
+	  "Amasya",
+	  "Antalya",
+	  "Artvin",
+	  "Balıkesir",
+	  "Bilecik",
+	  "Bitlis",
+	  "Bolu",
+	  "Burdur",
+	  "Bursa",
+	  "Çanakkale",
+	  "Çankırı",
+	  "Denizli",
+	  "Diyarbakır",
+	  "Edirne",
+	  "Elazığ",
+	  "Erzurum",
+	  "Eskişehir",
+	  "Giresun",
+	  "Gümüşhane",
+	  "Hatay",
+	  "Isparta",
+	  "İçel",
+	  "İstanbul",
+	  "İzmir",
+	  "Kars",
+	  "Kastamonu",
+	  "Kayseri",
+	  "Kırklareli",
+	  "Kocaeli",
+	  "Konya",
+	  "Kütahya",
+	  "Malatya",
+	  "Manisa",
+	  "Kahramanmaraş",
+	  "Mardin",
+	  "Muğla",
+	  "Muş",
+	  "Nevşehir",
+	  "Niğde",
+	  "Rize",
+	  "Sakarya",
+	  "Samsun",
+	  "Siirt",
+	  "Sinop",
+	  "Sivas",
+	  "Trabzon",
+	  "Şanlıurfa",
+	  "Van",
+	  "Yozgat",
+	  "Zonguldak",
+	  "Aksaray",
+	  "Bayburt",
+	  "Karaman",
+	  "Kırıkkale",
+	  "Batman",
+	  "Şırnak",
+	  "Bartın",
+	  "Ardahan",
+	  "Iğdır",
+	  "Yalova",
+	  "Karabük",
+	  "Osmaniye",
+	  "Düzce",
+];
+
+pub static NAME_ITALIAN_REAL: &[&str] = &[
+	  "Roma",
+	  "Milano",
+	  "Napoli",
+	  "Torino",
+	  "Venezia",
+	  "Firenze",
+	  "Palermo",
+	  "Genova",
+	  "Parma",
+	  "Bologna",
+	  "Bari",
+	  "Cagliari",
+	  "Sassari",
+	  "Pisa",
+	  "Aosta",
+	  "Brescia",
+	  "Verona",
+	  "Bolzano",
+	  "Padova",
+	  "Udine",
+	  "Trieste",
+	  "Livorno",
+	  "Ancona",
+	  "Perugia",
+	  "Pescara",
+	  "L'Aquila",
+	  "Campobasso",
+	  "Potenza",
+	  "Cosenza",
+	  "Reggio Calabria",
+	  "Catania",
+	  "Messina",
+	  "Trapani",
+	  "Siracusa",
+	  "Agrigento",
+	  "Ragusa",
+	  "Enna",
+	  "Caltanissetta",
+	  "Lecce",
+	  "Taranto",
+	  "Foggia",
+	  "Brindisi",
+	  "Matera",
+	  "Salerno",
+	  "Avellino",
+	  "Benevento",
+	  "Caserta",
+	  "Latina",
+	  "Frosinone",
+	  "Rieti",
+	  "Viterbo",
+	  "Terni",
+	  "Arezzo",
+	  "Siena",
+	  "Grosseto",
+	  "Lucca",
+	  "Pistoia",
+	  "Prato",
+	  "Massa",
+	  "Carrara",
+	  "La Spezia",
+	  "Savona",
+	  "Imperia",
+	  "Cuneo",
+	  "Asti",
+	  "Alessandria",
+	  "Novara",
+	  "Vercelli",
+	  "Biella",
+	  "Como",
+	  "Lecco",
+	  "Varese",
+	  "Sondrio",
+	  "Bergamo",
+	  "Cremona",
+	  "Mantova",
+	  "Lodi",
+	  "Pavia",
+	  "Piacenza",
+	  "Reggio Emilia",
+	  "Modena",
+	  "Ferrara",
+	  "Ravenna",
+	  "Forlì",
+	  "Rimini",
+	  "Trento",
+	  "Belluno",
+	  "Treviso",
+	  "Vicenza",
+	  "Rovigo",
+	  "Gorizia",
+	  "Pordenone",
+];
+
+pub static NAME_ITALIAN_PREF: &[&str] = &[
+	  "Alpe ",
+	  "Borgo ",
+	  "Cascina ",
+	  "Castel ",
+	  "Fonte ",
+	  "Marina ",
+	  "Porto ",
+	  "Rocca ",
+	  "San ",
+	  "Santa ",
+	  "Santo ",
+	  "Sant'",
+	  "Torre ",
+	  "Villa ",
+];
+
+pub static NAME_ITALIAN_1M: &[&str] = &[
+	  "Bel",
+	  "Borgo",
+	  "Bosco",
+	  "Campo",
+	  "Capo",
+	  "Casal",
+	  "Castel",
+	  "Colle",
+	  "Fiume",
+	  "Fonte",
+	  "Lago",
+	  "Mezzo",
+	  "Monte",
+	  "Mon",
+	  "Orto",
+	  "Passo",
+	  "Prato",
+	  "Poggio",
+	  "Ponte",
+	  "Pozzo",
+	  "Sasso",
+	  "Tra",
+	  "Tre",
+	  "Ver",
+	  "Vico",
+];
+
+pub static NAME_ITALIAN_1F: &[&str] = &[
+	  "Acqua",
+	  "Bra",
+	  "Cala",
+	  "Casa",
+	  "Chiesa",
+	  "Costa",
+	  "Croce",
+	  "Fonta",
+	  "Galla",
+	  "Lama",
+	  "Mezza",
+	  "Monta",
+	  "Orta",
+	  "Penna",
+	  "Pietra",
+	  "Rocca",
+	  "Sala",
+	  "Serra",
+	  "Sesta",
+	  "Torre",
+	  "Valle",
+	  "Villa",
+];
+
+pub static NAME_ITALIAN_2: &[&str] = &[
+	  "bell",
+	  "bianc",
+	  "cald",
+	  "chiar",
+	  "cort",
+	  "ferrat",
+	  "fier",
+	  "fredd",
+	  "gioios",
+	  "grec",
+	  "lung",
+	  "migli",
+	  "negr",
+	  "ner",
+	  "nov",
+	  "nuov",
+	  "ross",
+	  "sant",
+	  "scur",
+	  "sicur",
+	  "vecch",
+];
+
+pub static NAME_ITALIAN_2I: &[&str] = &[
+	  "",
+	  "breve",
+	  "brevi",
+	  "chiari",
+	  "ferro",
+	  "fieschi",
+	  "fiore",
+	  "fonte",
+	  "forte",
+	  "leone",
+	  "maggiore",
+	  "minore",
+	  "mare",
+	  "monte",
+	  "nello",
+	  "pagano",
+	  "paldo",
+	  "pietra",
+	  "porto",
+	  "sale",
+	  "salvo",
+	  "santo",
+	  "sette",
+];
+
+pub static NAME_ITALIAN_3: &[&str] = &[
+	  " Marittimo",
+	  " Marittima",
+	  " del Capo",
+	  " del Monte",
+	  " di Sopra",
+	  " di Sotto",
+	  " Monte",
+	  " Montalto",
+	  " Montagna",
+	  " Montano",
+	  " Montana",
+	  " Nuovo",
+	  " Nuova",
+	  " Superiore",
+	  " Inferiore",
+	  " Scalo",
+	  " Terme",
+];
+
+pub static NAME_ITALIAN_RIVER1: &[&str] = &[
+	  " del",
+	  " sul",
+	  " al",
+	  " nel",
+];
+
+pub static NAME_ITALIAN_RIVER2: &[&str] = &[
+	  "l'Adda",
+	  "l'Adige",
+	  "le Alpi",
+	  "l'Arno",
+	  " Bormida",
+	  " Brenta",
+	  "la Dora Baltea",
+	  " Lambro",
+	  " Mincio",
+	  " Naviglio",
+	  "l'Oglio",
+	  "l'Olona",
+	  "l'Ombrone",
+	  " Panaro",
+	  " Piave",
+	  " Po",
+	  " Reno",
+	  " Scrivia",
+	  " Secchia",
+	  " Serio",
+	  " Tagliamento",
+	  " Tanaro",
+	  " Taro",
+	  " Ticino",
+	  " Tevere",
+];
+
+pub static NAME_CATALAN_REAL: &[&str] = &[
+	  "Barcelona",
+	  "Hospitalet",
+	  "Cerdanyola",
+	  "Martorell",
+	  "Badalona",
+	  "Tarragona",
+	  "Lleida",
+	  "Girona",
+	  "Sabadell",
+	  "Terrassa",
+	  "Reus",
+	  "Valls",
+	  "Vic",
+	  "Vielha",
+	  "Amposta",
+	  "Tortosa",
+	  "Berga",
+	  "Olot",
+	  "Mollerussa",
+	  "Banyoles",
+	  "Figueres",
+	  "Balaguer",
+	  "Vilafranca del Penedès",
+	  "La Seu d'Urgell",
+	  "Pont de Suert",
+	  "Igualada",
+	  "Manlleu",
+	  "Vilanova i la Geltrú",
+	  "Sitges",
+	  "Mataró",
+	  "Granollers",
+	  "Sant Cugat",
+	  "Manresa",
+	  "Salou",
+	  "Cambrils",
+	  "Calella",
+	  "Palafrugell",
+	  "Blanes",
+	  "Lloret de Mar",
+	  "Tàrrega",
+	  "Solsona",
+	  "Ripoll",
+	  "Puigcerdà",
+	  "Camprodon",
+	  "Cervera",
+];
+
+pub static NAME_CATALAN_PREF: &[&str] = &[
+	  "Pont de ",
+	  "Parets de ",
+	  "Sant ",
+	  "Santa ",
+	  "Torre ",
+	  "Vila",
+	  "Vilanova de ",
+	  "Castell de ",
+	  "Mas ",
+	  "Coll de ",
+	  "Pla de ",
+	  "Vall de ",
+];
+
+pub static NAME_CATALAN_1M: &[&str] = &[
+	  "Torrent",
+	  "Camí",
+	  "Mont",
+	  "Bosc",
+	  "Pont",
+	  "Riu",
+	  "Castell",
+	  "Pla",
+	  "Coll",
+	  "Puig",
+	  "Turó",
+	  "Camp",
+	  "Prat",
+	  "Pi",
+];
+
+pub static NAME_CATALAN_1F: &[&str] = &[
+	  "Aigua",
+	  "Creu",
+	  "Riba",
+	  "Torre",
+	  "Font",
+	  "Muntanya",
+	  "Vall",
+	  "Serra",
+	  "Plana",
+	  "Pedra",
+	  "Costa",
+	  "Roca",
+	  "Vinya",
+	  "Baga",
+];
+
+pub static NAME_CATALAN_2M: &[&str] = &[
+	  "alt",
+	  "baix",
+	  "blanc",
+	  "gran",
+	  "nou",
+	  "vell",
+	  "negre",
+	  "roig",
+	  "verd",
+	  "petit",
+	  "fosc",
+	  "llarg",
+];
+
+pub static NAME_CATALAN_2F: &[&str] = &[
+	  "alta",
+	  "baixa",
+	  "blanca",
+	  "gran",
+	  "nova",
+	  "vella",
+	  "negra",
+	  "roja",
+	  "verda",
+	  "petita",
+	  "fosca",
+	  "llarga",
+];
+
+pub static NAME_CATALAN_3: &[&str] = &[
+	  " del Vallès",
+	  " de Mar",
+	  " del Penedès",
+	  " de Segarra",
+	  " d'Urgell",
+	  " de Cerdanya",
+	  " del Camp",
+	  " de Montserrat",
+	  " de la Selva",
+	  " d'Empordà",
+];
+
+pub static NAME_CATALAN_RIVER1: &[&str] = &[
+	  " d'Ebre",
+	  " de Ter",
+	  " de Segre",
+	  " de Llobregat",
+	  " de Fluvià",
+	  " de Besòs",
+	  " de Noguera",
+	  " de Foix",
+];
@@ -31,7 +31,180 @@ fn save1() -> Result<(), Error> {
         inaugurated_year: 1950
     }, cp);
 
-    //let vehicles = sv.vehicles()?;
+    let vehicles = sv.vehicles()?;
+
+    assert_eq!(137, vehicles.trains.len());
+    assert_eq!(16, vehicles.trains.iter().filter(|t| t.is_front()).count());
+    assert_eq!(26_805_354, vehicles.trains.iter().find(|t| t.vehicle.unit_number == 7).unwrap().vehicle.profit_last_year.original);
+    assert_eq!(2, vehicles.road_vehicles.len());
+    assert_eq!(4, vehicles.ships.len());
+    assert_eq!(5, vehicles.aircraft.iter().filter(|a| a.is_normal_aircraft()).count());
+    assert_eq!(3, vehicles.aircraft.iter().filter(|a| a.state == AircraftState::Flying).count());
+
+    let consists = Consist::list(&vehicles.trains)?;
+
+    assert_eq!(16, consists.len());
+    let livestock = consists.iter().find(|c| c.front.vehicle.unit_number == 16).unwrap();
+    assert_eq!(13, livestock.parts.len());
+    assert_eq!(7, livestock.length_in_tiles());
+    assert_eq!(std::collections::BTreeMap::from([(4, 300)]), livestock.capacities());
+    assert_eq!(81_373, livestock.value().original);
+    assert_eq!(41, livestock.cargo_weight(Climate::Temperate));
+
+    let order_lists = sv.order_lists()?;
+
+    assert_eq!(27, order_lists.len());
+    assert_eq!(71, order_lists.iter().map(|l| l.orders.len()).sum::<usize>());
+    assert!(order_lists.iter().all(|l| l.vehicles.len() == 1));
+    assert_eq!(vec![livestock.front.vehicle.index], order_lists[25].vehicles);
+    assert_eq!(Some(OrderLoad::FullLoadAny), order_lists[25].orders[0].load());
+    assert_eq!(8, order_lists.iter().flat_map(|l| l.orders.iter()).filter(|o| o.order_type == OrderType::GoToWaypoint).count());
+
+    let timetables = sv.timetables()?;
+
+    assert_eq!(27, timetables.len());
+    assert!(timetables.iter().all(|t| !t.is_complete()));
+    assert!(timetables.iter().all(|t| t.late_vehicles().is_empty()));
+    assert_eq!(222 + 1628 + 1332 + 2 * 592 + 17094 + 1184 + 518, timetables[6].estimated_duration());
+
+    let engines = sv.engines()?;
+
+    assert_eq!(256, engines.len());
+    assert!(engines.iter().all(|e| e.id.original_id() == Some(e.index)));
+    assert_eq!(Some("UU '37' (Diesel)"), engines[14].name(Climate::Temperate));
+    assert_eq!(None, engines[1].spec(Climate::Temperate));
+    // The engines of the company and of his vehicles are built in the climate
+    assert!(engines.iter().filter(|e| e.is_available_to(0)).all(|e| e.spec(Climate::Temperate).is_some()));
+    assert!(vehicles.iter().all(|(_, v)| engines[v.engine_type as usize].spec(Climate::Temperate).is_some()));
+
+    let towns = sv.towns()?;
+
+    assert_eq!(448, towns.len());
+    assert_eq!("Drenninghall".to_string(), towns[0].name);
+    assert_eq!(116, towns.iter().filter(|t| t.is_city).count());
+    assert_eq!(Some(754), towns[0].population);
+    assert_eq!(Some(2904), towns[19].population);
+    assert_eq!(Some(451_932), towns.iter().map(|t| t.population).sum::<Option<u32>>());
+    assert_eq!(14, towns.iter().filter(|t| !t.ratings.is_empty()).count());
+    assert_eq!(TownRating::Outstanding, towns[19].ratings[0].rating());
+    assert_eq!(35, towns[19].passengers.percent_transported());
+    assert_eq!(12, towns[19].mail.percent_transported());
+
+    let settings = sv.settings()?;
+
+    assert_eq!(Climate::Temperate, settings.climate);
+
+    let today = sv.date()?;
+
+    assert_eq!(1998, today.year());
+
+    let industries = sv.industries()?;
+
+    assert_eq!(889, industries.len());
+    assert_eq!(Some(428), industries[0].town);
+    assert_eq!(Date(725005), industries[0].construction_date);
+    assert_eq!("Oil Refinery", industries[0].spec(settings.climate).unwrap().name);
+
+    assert_eq!(8, industries.iter().filter(|i| i.produced.iter().any(|p| p.last_month_transported > 0)).count());
+    assert!(industries.iter().filter(|i| i.closure_risks(today, settings.climate).contains(&ClosureRisk::LowService)).count() > 0);
+
+    // The cargos of the industries match the default ones
+    for industry in &industries {
+        let spec = industry.spec(settings.climate).unwrap();
+        assert_eq!(spec.produced, industry.produced.iter().map(|p| p.cargo).collect::<Vec<u8>>());
+        assert_eq!(spec.accepted, industry.accepted);
+    }
+
+    let stations = sv.stations()?;
+
+    assert_eq!(73, stations.len());
+    assert_eq!("Gewood North", stations[0].name);
+    assert_eq!(15, stations[0].tiles.len());
+    assert_eq!("Gewood Buoy #2", stations[60].name);
+    assert_eq!(2, stations.iter().filter(|s| s.is_waypoint()).count());
+    assert_eq!(48, stations.iter().filter(|s| s.owner == Owner::None && !s.is_waypoint()).count());
+    assert!(stations.iter().all(|s| s.tiles.contains(&s.xy) || s.is_waypoint() || s.facilities.dock));
+
+    let airport = stations[1].airport.as_ref().unwrap();
+    assert_eq!(AirportType::International, airport.airport_type);
+    assert_eq!((7, 7), (airport.width, airport.height));
+    assert_eq!(vec![893976 + 3 * 1024, 893976 + 1024 + 6], airport.hangars);
+    assert_eq!(2, stations.iter().filter(|s| s.airport.as_ref().is_some_and(|a| !a.hangars.is_empty())).count());
+    assert_eq!(vec![stations[4].xy], stations[4].dock_tiles);
+    assert_eq!(Some(AirportType::OilRig), stations[4].airport.as_ref().map(|a| a.airport_type));
+    assert_eq!(2, stations[57].dock_tiles.len());
+
+    let passengers = &stations[0].goods[0];
+    assert_eq!(1934, passengers.waiting);
+    assert_eq!(32, passengers.rating_percent());
+    let neglected = stations.iter()
+        .filter(|s| s.goods.iter().any(|g| g.is_neglected()))
+        .map(|s| s.index)
+        .collect::<Vec<usize>>();
+    assert_eq!(vec![2, 3, 39], neglected);
+
+    let packets = sv.cargo_packets()?;
+
+    assert_eq!(279, packets.len());
+    let waiting = CargoPacket::find(&packets, &stations[0].goods[0].packets);
+    assert_eq!(7, waiting.len());
+    assert_eq!(6, waiting.iter().filter(|p| p.source_station == Some(10) && p.is_transferred()).count());
+
+    let graphs = sv.link_graphs()?;
+
+    assert_eq!(8, graphs.len());
+    assert_eq!(17, graphs[0].nodes.len());
+    assert_eq!(5, sv.link_graph_jobs()?.len());
+    assert_eq!(vec![1, 2, 5], sv.link_graph_schedule()?.scheduled);
+    assert!(graphs[0].to_dot(&stations).contains("    0 [label=\"Gewood North\", supply=432, demand=20];\n"));
+
+    let map = sv.map()?;
+
+    assert_eq!(1024, map.width);
+    assert_eq!(1024, map.height);
+    assert_eq!(0x70, map.tile(0, 0).unwrap().tile_type);
+    assert_eq!(RawTile {
+        tile_type: 0x10,
+        height: 1,
+        m1: 0,
+        m2: 512,
+        m3: 0,
+        m4: 1,
+        m5: 10,
+        m6: 0,
+        m7: 0,
+        m8: 0
+    }, map.tile(31, 887).unwrap());
+
+    let tile = Tile::try_from(map.tile(31, 887).unwrap())?;
+    assert_eq!(Some(Owner::Company(0)), tile.owner());
+    assert_eq!(Some(10), tile.track_bits());
+    assert_eq!(Tile::Void(RawTile { tile_type: 0x70, ..RawTile::default() }), Tile::try_from(map.tile(0, 0).unwrap())?);
+
+    let houses = House::list_by_town(&map);
+
+    assert_eq!(448, houses.len());
+    assert_eq!(13369, houses.values().map(|h| h.len()).sum::<usize>());
+    assert_eq!(116, houses.values().flatten().filter(|h| !h.is_completed()).count());
+
+    let footprint = TownFootprint::new(&towns[0], &houses[&0], &map);
+    assert_eq!(22, footprint.houses);
+    assert_eq!((138, 224), footprint.center);
+    assert_eq!((131, 216), footprint.min);
+    assert_eq!((139, 226), footprint.max);
+    assert_eq!(22, footprint.houses_per_zone.iter().sum::<usize>());
+
+    #[cfg(feature = "render")]
+    {
+        let image = MapImage::render(&map, RenderMode::Transport)?;
+        assert_eq!(1024 * 1024 * 3, image.pixels.len());
+
+        let heightmap = Heightmap::from_map(&map, 30)?;
+        assert_eq!(1, heightmap.levels[887 * 1024 + (1023 - 31)]);
+
+        let terrain = heightmap.to_map(30)?;
+        assert_eq!(map.tile_height[887 * 1024 + 31], terrain.tile_height[887 * 1024 + 31]);
+    }
 
     Ok(())
 }
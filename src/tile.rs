@@ -0,0 +1,284 @@
+//! Tile related types
+//!
+//! Decoding of the tile fields, following the layout
+//! of OpenTTD 1.8 savegames.
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/docs/landscape.html
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/tile_map.h
+
+use crate::bitmath::{gb, has_bit};
+use crate::error::Error;
+use crate::map::RawTile;
+
+/// Decoded tile, by his type
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Tile {
+    /// A tile without anything on it
+    Clear(RawTile),
+    /// A railway tile, with or without signals, or a depot
+    Railway(RawTile),
+    /// A road tile, a level crossing or a road depot
+    Road(RawTile),
+    /// A house of a town
+    House(RawTile),
+    /// A tile with trees
+    Trees(RawTile),
+    /// A station, waypoint, buoy or part of an airport
+    Station(RawTile),
+    /// Sea, canals, rivers, coasts, locks and ship depots
+    Water(RawTile),
+    /// Invisible tiles at the SW and SE border
+    Void(RawTile),
+    /// Part of an industry
+    Industry(RawTile),
+    /// Entrance of a tunnel or head of a bridge
+    TunnelBridge(RawTile),
+    /// Transmitters, lighthouses, company HQ and others objects
+    Object(RawTile),
+}
+
+/// Owner of a tile
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Owner {
+    /// One of the companies
+    Company(u8),
+    /// A town owns the tile
+    Town,
+    /// No owner
+    None,
+    /// The water
+    Water,
+    /// The game script
+    Deity,
+}
+
+impl From<u8> for Owner {
+    fn from(owner: u8) -> Self {
+        match owner {
+            0x0F => Owner::Town,
+            0x11 => Owner::Water,
+            0x12 => Owner::Deity,
+            o if o < 0x0F => Owner::Company(o),
+            _ => Owner::None
+        }
+    }
+}
+
+/// Kind of the water on a tile
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum WaterClass {
+    Sea,
+    Canal,
+    River,
+    Invalid,
+}
+
+impl From<u32> for WaterClass {
+    fn from(class: u32) -> Self {
+        match class {
+            0 => WaterClass::Sea,
+            1 => WaterClass::Canal,
+            2 => WaterClass::River,
+            _ => WaterClass::Invalid
+        }
+    }
+}
+
+impl TryFrom<RawTile> for Tile {
+    type Error = Error;
+
+    fn try_from(raw: RawTile) -> Result<Self, Self::Error> {
+        // The type is on the bits 4..7
+        match gb(raw.tile_type as u32, 4, 4) {
+            0 => Ok(Tile::Clear(raw)),
+            1 => Ok(Tile::Railway(raw)),
+            2 => Ok(Tile::Road(raw)),
+            3 => Ok(Tile::House(raw)),
+            4 => Ok(Tile::Trees(raw)),
+            5 => Ok(Tile::Station(raw)),
+            6 => Ok(Tile::Water(raw)),
+            7 => Ok(Tile::Void(raw)),
+            8 => Ok(Tile::Industry(raw)),
+            9 => Ok(Tile::TunnelBridge(raw)),
+            10 => Ok(Tile::Object(raw)),
+            e => Err(Error::DataCorruption(format!("Unknown tile type: {}", e)))
+        }
+    }
+}
+
+impl Tile {
+
+    /// Return the raw fields of the tile
+    pub fn raw(&self) -> &RawTile {
+        match self {
+            Tile::Clear(raw) | Tile::Railway(raw) | Tile::Road(raw) | Tile::House(raw)
+                | Tile::Trees(raw) | Tile::Station(raw) | Tile::Water(raw) | Tile::Void(raw)
+                | Tile::Industry(raw) | Tile::TunnelBridge(raw) | Tile::Object(raw) => raw
+        }
+    }
+
+    /// Height of the northern corner
+    pub fn height(&self) -> u8 {
+        self.raw().height
+    }
+
+    /// Owner of the tile
+    ///
+    /// Houses, industries and void tiles have no owner
+    pub fn owner(&self) -> Option<Owner> {
+        match self {
+            Tile::House(_) | Tile::Industry(_) | Tile::Void(_) => None,
+            t => Some(Owner::from(gb(t.raw().m1 as u32, 0, 5) as u8))
+        }
+    }
+
+    /// Rail tracks on the tile, as the TrackBits flags
+    ///
+    /// Only for railway tiles, without depots, and level crossings
+    pub fn track_bits(&self) -> Option<u8> {
+        match self {
+            Tile::Railway(raw) => match gb(raw.m5 as u32, 6, 2) {
+                // RAIL_TILE_NORMAL and RAIL_TILE_SIGNALS
+                0 | 1 => Some(gb(raw.m5 as u32, 0, 6) as u8),
+                _ => None
+            },
+            Tile::Road(raw) if is_level_crossing(raw) => {
+                // The rail crosses the road axis
+                if has_bit(raw.m5 as u32, 0) {
+                    Some(0x01) // TRACK_BIT_X
+                } else {
+                    Some(0x02) // TRACK_BIT_Y
+                }
+            },
+            _ => None
+        }
+    }
+
+    /// Road pieces on the tile, as the RoadBits flags
+    ///
+    /// Only for normal road tiles and level crossings
+    pub fn road_bits(&self) -> Option<u8> {
+        match self {
+            Tile::Road(raw) => match gb(raw.m5 as u32, 6, 2) {
+                // ROAD_TILE_NORMAL
+                0 => Some(gb(raw.m5 as u32, 0, 4) as u8),
+                // ROAD_TILE_CROSSING
+                1 => if has_bit(raw.m5 as u32, 0) {
+                    Some(0x05) // ROAD_Y
+                } else {
+                    Some(0x0A) // ROAD_X
+                },
+                _ => None
+            },
+            _ => None
+        }
+    }
+
+    /// Index of the station, waypoint or buoy
+    pub fn station_index(&self) -> Option<u16> {
+        match self {
+            Tile::Station(raw) => Some(raw.m2),
+            _ => None
+        }
+    }
+
+    /// Index of the industry
+    pub fn industry_index(&self) -> Option<u16> {
+        match self {
+            Tile::Industry(raw) => Some(raw.m2),
+            _ => None
+        }
+    }
+
+    /// Kind of water on, or under, the tile
+    pub fn water_class(&self) -> Option<WaterClass> {
+        match self {
+            Tile::Water(raw) | Tile::Station(raw) | Tile::Industry(raw) | Tile::Object(raw) => {
+                Some(WaterClass::from(gb(raw.m1 as u32, 5, 2)))
+            },
+            _ => None
+        }
+    }
+}
+
+/// Check if the road tile is a level crossing
+fn is_level_crossing(raw: &RawTile) -> bool {
+    gb(raw.m5 as u32, 6, 2) == 1
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::map::RawTile;
+    use crate::tile::*;
+
+    #[test]
+    fn railway() -> Result<(), String> {
+
+        let tile = Tile::try_from(RawTile {
+            tile_type: 0x10,
+            height: 1,
+            m2: 512,
+            m4: 1,
+            m5: 10,
+            ..RawTile::default()
+        }).map_err(|e| e.to_string())?;
+
+        assert!(matches!(tile, Tile::Railway(_)));
+        assert_eq!(1, tile.height());
+        assert_eq!(Some(Owner::Company(0)), tile.owner());
+        assert_eq!(Some(10), tile.track_bits());
+        assert_eq!(None, tile.road_bits());
+        assert_eq!(None, tile.water_class());
+
+        Ok(())
+    }
+
+    #[test]
+    fn level_crossing() -> Result<(), String> {
+
+        let tile = Tile::try_from(RawTile {
+            tile_type: 0x20,
+            m1: 0x0F,
+            m5: 0x41,
+            ..RawTile::default()
+        }).map_err(|e| e.to_string())?;
+
+        assert_eq!(Some(Owner::Town), tile.owner());
+        assert_eq!(Some(0x05), tile.road_bits());
+        assert_eq!(Some(0x01), tile.track_bits());
+
+        Ok(())
+    }
+
+    #[test]
+    fn water_and_industry() -> Result<(), String> {
+
+        let river = Tile::try_from(RawTile {
+            tile_type: 0x60,
+            m1: 0x51,
+            ..RawTile::default()
+        }).map_err(|e| e.to_string())?;
+
+        assert_eq!(Some(Owner::Water), river.owner());
+        assert_eq!(Some(WaterClass::River), river.water_class());
+
+        let industry = Tile::try_from(RawTile {
+            tile_type: 0x80,
+            m2: 42,
+            ..RawTile::default()
+        }).map_err(|e| e.to_string())?;
+
+        assert_eq!(None, industry.owner());
+        assert_eq!(Some(42), industry.industry_index());
+        assert_eq!(None, industry.station_index());
+
+        assert!(Tile::try_from(RawTile {
+            tile_type: 0xF0,
+            ..RawTile::default()
+        }).is_err());
+
+        Ok(())
+    }
+}
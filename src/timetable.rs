@@ -0,0 +1,311 @@
+//! Timetable related types
+//!
+//! The times are saved on the orders, set by the player or
+//! measured by the game, and the delay is saved on the vehicles.
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/timetable_cmd.cpp
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/timetable_gui.cpp
+
+use crate::date::Date;
+use crate::error::Error;
+use crate::order::{Order, OrderList, OrderType};
+use crate::vehicle::{Vehicles, VehicleType};
+
+/// Ticks of a day
+const DAY_TICKS: i32 = 74;
+
+/// Time of an order on the timetable, in ticks
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TimetableTime {
+    /// Set by the player
+    Scheduled(u16),
+    /// Not set, the last time measured by the game
+    Measured(u16),
+    /// Not set and never measured
+    Unknown,
+}
+
+impl TimetableTime {
+
+    fn new(time: u16, timetabled: bool) -> TimetableTime {
+        match (time, timetabled) {
+            (t, true) => TimetableTime::Scheduled(t),
+            (0, false) => TimetableTime::Unknown,
+            (t, false) => TimetableTime::Measured(t)
+        }
+    }
+
+    /// Time set by the player
+    pub fn scheduled(&self) -> Option<u16> {
+        match self {
+            TimetableTime::Scheduled(t) => Some(*t),
+            _ => None
+        }
+    }
+
+    /// Time set by the player, or the measured one
+    pub fn estimated(&self) -> Option<u16> {
+        match self {
+            TimetableTime::Scheduled(t) | TimetableTime::Measured(t) => Some(*t),
+            TimetableTime::Unknown => None
+        }
+    }
+}
+
+/// Times of an order
+#[derive(Debug, PartialEq, Clone)]
+pub struct TimetableEntry {
+    /// Position of the order on the list, with the implicit orders
+    pub order: usize,
+    /// Destination station, or waypoint
+    pub station: Option<usize>,
+    /// Travel time to the destination
+    pub travel: TimetableTime,
+    /// Waiting time at the destination
+    pub wait: TimetableTime,
+}
+
+impl TimetableEntry {
+
+    fn new(order: usize, o: &Order) -> TimetableEntry {
+        TimetableEntry {
+            order,
+            station: o.station(),
+            travel: TimetableTime::new(o.travel_time, o.is_travel_timetabled()),
+            wait: TimetableTime::new(o.wait_time, o.is_wait_timetabled())
+        }
+    }
+}
+
+/// Progress of a vehicle on the timetable
+#[derive(Debug, PartialEq, Clone)]
+pub struct VehicleTimetable {
+    /// Index of the vehicle on the pool
+    pub vehicle: usize,
+    /// Type of the vehicle
+    pub vehicle_type: VehicleType,
+    /// Number of the vehicle shown by the game
+    pub unit_number: u16,
+    /// Engine of the vehicle, the first part for the trains
+    pub engine_type: u16,
+    /// Position of the current order on the entries, none
+    /// when the vehicle points to an unknown order
+    pub current_entry: Option<usize>,
+    /// Ticks since the start of the current order
+    pub current_order_time: u32,
+    /// Date of the first order, when set by the player
+    pub start_date: Option<Date>,
+    /// The vehicle is running on the timetable
+    pub started: bool,
+    /// Ticks of delay, negative when early
+    pub lateness: i32,
+}
+
+impl VehicleTimetable {
+
+    /// Days of delay, negative when early
+    pub fn lateness_days(&self) -> i32 {
+        self.lateness / DAY_TICKS
+    }
+
+    /// Check if the vehicle is at least one day late
+    pub fn is_late(&self) -> bool {
+        self.lateness_days() > 0
+    }
+
+    /// Check if the vehicle is at least one day early
+    pub fn is_early(&self) -> bool {
+        self.lateness_days() < 0
+    }
+}
+
+/// Timetable of an order list, with his vehicles
+#[derive(Debug, PartialEq)]
+pub struct Timetable {
+    /// Index of the order list
+    pub order_list: usize,
+    /// Times of the orders, without the implicit ones
+    pub entries: Vec<TimetableEntry>,
+    /// Vehicles sharing the orders
+    pub vehicles: Vec<VehicleTimetable>,
+}
+
+impl Timetable {
+
+    /// Timetables of all the order lists
+    pub fn parse_list(buffer: &[u8]) -> Result<Vec<Timetable>, Error> {
+
+        let vehicles = Vehicles::parse(buffer)?;
+        let lists = OrderList::parse_list(buffer, &vehicles)?;
+
+        Ok(lists.iter()
+            .map(|l| Timetable::new(l, &vehicles))
+            .collect())
+    }
+
+    /// Timetable of an order list
+    pub fn new(list: &OrderList, vehicles: &Vehicles) -> Timetable {
+
+        let entries: Vec<TimetableEntry> = list.orders.iter()
+            .enumerate()
+            .filter(|(_, o)| o.order_type != OrderType::Implicit)
+            .map(|(i, o)| TimetableEntry::new(i, o))
+            .collect();
+
+        let vehicles = vehicles.iter()
+            .filter(|(_, v)| v.order_list == Some(list.index))
+            .map(|(vehicle_type, v)| VehicleTimetable {
+                vehicle: v.index,
+                vehicle_type,
+                unit_number: v.unit_number,
+                engine_type: v.engine_type,
+                current_entry: entries.iter().position(|e| e.order == v.current_order as usize),
+                current_order_time: v.current_order_time,
+                start_date: v.timetable_start,
+                started: v.is_timetable_started(),
+                lateness: v.lateness_counter
+            })
+            .collect();
+
+        Timetable {
+            order_list: list.index,
+            entries,
+            vehicles
+        }
+    }
+
+    /// Check if all the times are set by the player
+    pub fn is_complete(&self) -> bool {
+        self.entries.iter()
+            .all(|e| e.travel.scheduled().is_some() && e.wait.scheduled().is_some())
+    }
+
+    /// Ticks of a full round, when all the times are set by the player
+    pub fn scheduled_duration(&self) -> Option<u32> {
+        self.entries.iter()
+            .map(|e| Some(e.travel.scheduled()? as u32 + e.wait.scheduled()? as u32))
+            .sum()
+    }
+
+    /// Ticks of a full round, with the measured times of the
+    /// orders not set by the player
+    pub fn estimated_duration(&self) -> u32 {
+        self.entries.iter()
+            .map(|e| e.travel.estimated().unwrap_or(0) as u32 + e.wait.estimated().unwrap_or(0) as u32)
+            .sum()
+    }
+
+    /// Days of a full round, with the measured times
+    pub fn estimated_duration_days(&self) -> u32 {
+        self.estimated_duration() / DAY_TICKS as u32
+    }
+
+    /// Vehicles at least one day late
+    pub fn late_vehicles(&self) -> Vec<&VehicleTimetable> {
+        self.vehicles.iter()
+            .filter(|v| v.is_late())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::chunk_reader::ChunkReader;
+    use crate::order::test::{ORDR, ORDL};
+    use crate::timetable::*;
+    use crate::vehicle::test::{VEHS_TYPES, CAPA};
+
+    fn parse(ordr: &[u8], vehs: &[u8]) -> Result<Timetable, String> {
+
+        let orders = Order::parse_list(ordr)
+            .map_err(|e| e.to_string())?;
+
+        // The bus of the fixture has the second list
+        let chunk = ChunkReader::find(&ORDL, "ORDL")
+            .map_err(|e| e.to_string())?;
+        let mut chunk = chunk.advance_slice()
+            .map_err(|e| e.to_string())?
+            .ok_or("Missing list")?;
+        let list = OrderList::parse(&mut chunk, &orders)
+            .map_err(|e| e.to_string())?;
+
+        let vehicles = Vehicles::parse(&[vehs, &CAPA[..]].concat())
+            .map_err(|e| e.to_string())?;
+
+        Ok(Timetable::new(&list, &vehicles))
+    }
+
+    #[test]
+    fn base() -> Result<(), String> {
+
+        let timetable = parse(&ORDR, &VEHS_TYPES)?;
+
+        assert_eq!(1, timetable.order_list);
+
+        // Without the implicit order
+        assert_eq!(vec![0, 2, 3], timetable.entries.iter().map(|e| e.order).collect::<Vec<usize>>());
+        assert_eq!(TimetableEntry {
+            order: 0,
+            station: Some(0),
+            travel: TimetableTime::Measured(666),
+            wait: TimetableTime::Measured(296)
+        }, timetable.entries[0]);
+        assert!(!timetable.is_complete());
+        assert_eq!(None, timetable.scheduled_duration());
+        assert_eq!(666 + 296 + 962 + 296 + 444 + 296, timetable.estimated_duration());
+        assert_eq!(40, timetable.estimated_duration_days());
+
+        assert_eq!(VehicleTimetable {
+            vehicle: 278,
+            vehicle_type: VehicleType::RoadVehicle,
+            unit_number: 1,
+            engine_type: 117,
+            current_entry: Some(0),
+            current_order_time: 355,
+            start_date: None,
+            started: true,
+            lateness: 0
+        }, timetable.vehicles[0]);
+        assert!(timetable.late_vehicles().is_empty());
+
+        // The current order is counted with the implicit one
+        let mut vehs = VEHS_TYPES.to_vec();
+        vehs[636] = 2; // cur_real_order_index of the bus
+        let timetable = parse(&ORDR, &vehs)?;
+        assert_eq!(Some(1), timetable.vehicles[0].current_entry);
+
+        vehs[636] = 5;
+        let timetable = parse(&ORDR, &vehs)?;
+        assert_eq!(None, timetable.vehicles[0].current_entry);
+
+        Ok(())
+    }
+
+    #[test]
+    fn lateness() -> Result<(), String> {
+
+        let mut ordr = ORDR.to_vec();
+        ordr[71] |= 0x88; // timetabled times of the first order
+
+        let mut vehs = VEHS_TYPES.to_vec();
+        vehs[726] = 0x01; // lateness_counter of the bus
+        vehs[727] = 0x28;
+
+        let timetable = parse(&ordr, &vehs)?;
+
+        assert_eq!(TimetableTime::Scheduled(666), timetable.entries[0].travel);
+        assert_eq!(TimetableTime::Scheduled(296), timetable.entries[0].wait);
+        assert_eq!(TimetableTime::Measured(962), timetable.entries[1].travel);
+
+        let bus = &timetable.vehicles[0];
+        assert_eq!(296, bus.lateness);
+        assert_eq!(4, bus.lateness_days());
+        assert!(bus.is_late());
+        assert!(!bus.is_early());
+        assert_eq!(vec![bus], timetable.late_vehicles());
+
+        Ok(())
+    }
+}
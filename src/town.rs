@@ -0,0 +1,558 @@
+//! Town related types
+
+use crate::chunk_reader::ChunkReader;
+use crate::error::Error;
+use crate::house::House;
+use crate::map::Map;
+use crate::names_generators::{TownName, NameGeneration};
+
+/// First string of the town names generators, one per language
+const SPECSTR_TOWNNAME_START: u16 = 0x20C0;
+
+/// Max number of companies of a game
+const MAX_COMPANIES: u8 = 15;
+
+/// No company, like the town without exclusive transport rights
+const INVALID_COMPANY: u8 = 0xFF;
+
+/// Number of cargo types of a game
+const NUM_CARGO: usize = 32;
+
+/// Cargo slots of the passengers and mail, the same on all climates
+const CT_PASSENGERS: usize = 0;
+const CT_MAIL: usize = 2;
+
+/// Town effects of the delivered cargo, from TE_BEGIN to TE_END
+const NUM_TE: usize = 6;
+const TE_GOODS: usize = 3;
+const TE_WATER: usize = 4;
+const TE_FOOD: usize = 5;
+
+/// Rating of a company by the town authority
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd)]
+pub enum TownRating {
+    Appalling,
+    VeryPoor,
+    Poor,
+    Mediocre,
+    Good,
+    VeryGood,
+    Excellent,
+    Outstanding,
+}
+
+impl TownRating {
+
+    /// Rating of the points, from -1000 to 1000
+    pub fn from_points(points: i16) -> TownRating {
+        match points {
+            p if p <= -400 => TownRating::Appalling,
+            p if p <= -200 => TownRating::VeryPoor,
+            p if p <= 0 => TownRating::Poor,
+            p if p <= 200 => TownRating::Mediocre,
+            p if p <= 400 => TownRating::Good,
+            p if p <= 600 => TownRating::VeryGood,
+            p if p <= 800 => TownRating::Excellent,
+            _ => TownRating::Outstanding,
+        }
+    }
+}
+
+/// Points of a company on the town authority
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AuthorityRating {
+    /// Index of the company
+    pub company: u8,
+    /// Rating points, from -1000 to 1000
+    pub points: i16,
+}
+
+impl AuthorityRating {
+
+    /// Rating of the company
+    pub fn rating(&self) -> TownRating {
+        TownRating::from_points(self.points)
+    }
+}
+
+/// Cargo generated by the town, and the part transported by the companies
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct TransportedCargo {
+    /// Cargo generated last month
+    pub old_max: u32,
+    /// Cargo generated this month
+    pub new_max: u32,
+    /// Cargo transported last month
+    pub old_act: u32,
+    /// Cargo transported this month
+    pub new_act: u32,
+}
+
+impl TransportedCargo {
+
+    /// Percent of the cargo transported last month
+    pub fn percent_transported(&self) -> u8 {
+        if self.old_max == 0 {
+            return 0;
+        }
+
+        (self.old_act as u64 * 100 / self.old_max as u64).min(100) as u8
+    }
+}
+
+/// Cargo delivered to the town
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct ReceivedCargo {
+    /// Cargo delivered last month
+    pub old_act: u16,
+    /// Cargo delivered this month
+    pub new_act: u16,
+    /// Cargo needed each month for the town to grow, zero when not needed
+    pub goal: u32,
+}
+
+/// Town informations
+#[derive(Debug, PartialEq, Default)]
+pub struct Town {
+    /// Index of the town on the pool
+    pub index: usize,
+    /// Tile index of the town center
+    pub xy: u32,
+    /// Town name, custom or generated
+    ///
+    /// The names generated by a NewGRF fall back to the index of the town.
+    pub name: String,
+    /// Cities grow faster than the others towns
+    pub is_city: bool,
+    /// Inhabitants of the houses, not saved but rebuilt from the map
+    ///
+    /// None until rebuilt, or when a house isn't on the built-in table.
+    pub population: Option<u32>,
+    /// Ticks between two growths of the town
+    pub growth_rate: u16,
+    /// Ticks until the next growth
+    pub grow_counter: u16,
+    /// Ticks until a house is replaced
+    pub time_until_rebuild: u16,
+    /// Months of the new buildings funded by a company
+    pub fund_buildings_months: u8,
+    /// Months of the road reconstruction
+    pub road_build_months: u8,
+    /// Ratings of the companies known by the town
+    pub ratings: Vec<AuthorityRating>,
+    /// Company with the exclusive transport rights
+    pub exclusivity: Option<u8>,
+    /// Months until the end of the exclusive transport rights
+    pub exclusive_counter: u8,
+    /// Companies with a statue in the town
+    pub statues: Vec<u8>,
+    /// Passengers of the town
+    pub passengers: TransportedCargo,
+    /// Mail of the town
+    pub mail: TransportedCargo,
+    /// Goods delivered to the town
+    pub goods: ReceivedCargo,
+    /// Water delivered to the town, needed to grow on the desert
+    pub water: ReceivedCargo,
+    /// Food delivered to the town, needed to grow on the desert and above the snow line
+    pub food: ReceivedCargo,
+}
+
+impl Town {
+
+    /// Parse all the towns
+    pub fn parse_list(buffer: &[u8]) -> Result<Vec<Town>, Error> {
+
+        let mut chunk = ChunkReader::find(buffer, "CITY")?; // 43 49 54 59
+
+        let mut towns = vec![];
+
+        loop {
+            // Empty slices are free indexes of the pool
+            if chunk.gamma > 0 {
+                towns.push(Town::parse(&mut chunk)?);
+            }
+
+            match chunk.advance_slice()? {
+                Some(c) => chunk = c,
+                None => break
+            }
+        }
+
+        Ok(towns)
+    }
+
+    /// Parse the town information
+    pub fn parse(chunk: &mut ChunkReader) -> Result<Town, Error> {
+
+        // Fields from https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/saveload/town_sl.cpp
+
+        let index = chunk.slice_idx;
+        let xy = chunk.fetch::<u32>()?; // xy
+
+        // Town name
+        let name = {
+            let grfid = chunk.fetch::<u32>()?; // townnamegrfid
+            let name_type = chunk.fetch::<u16>()?; // townnametype
+            let name_parts = chunk.fetch::<u32>()?; // townnameparts
+            let name = chunk.fetch::<String>()?; // name
+
+            if !name.is_empty() {
+                name
+            } else if grfid != 0 {
+                format!("#{}", index)
+            } else {
+                TownName::generate(name_type.wrapping_sub(SPECSTR_TOWNNAME_START), name_parts)?
+            }
+        };
+
+        chunk.advance::<u8>()?; // flags
+        let statues = chunk.fetch::<u16>()?; // statues
+        let have_ratings = chunk.fetch::<u16>()?; // have_ratings
+
+        let mut ratings = vec![];
+        for company in 0..MAX_COMPANIES {
+            let points = chunk.fetch::<i16>()?; // ratings
+
+            if have_ratings & (1 << company) != 0 {
+                ratings.push(AuthorityRating { company, points });
+            }
+        }
+        for _ in 0..15 {
+            chunk.advance::<i8>()?; // unwanted
+        }
+        let mut goals = [0_u32; NUM_TE];
+        for goal in goals.iter_mut() {
+            *goal = chunk.fetch::<u32>()?; // goal
+        }
+        chunk.advance::<String>()?; // text
+
+        let time_until_rebuild = chunk.fetch::<u16>()?; // time_until_rebuild
+        let grow_counter = chunk.fetch::<u16>()?; // grow_counter
+        let growth_rate = chunk.fetch::<u16>()?; // growth_rate
+
+        let fund_buildings_months = chunk.fetch::<u8>()?; // fund_buildings_months
+        let road_build_months = chunk.fetch::<u8>()?; // road_build_months
+
+        let exclusivity = match chunk.fetch::<u8>()? { // exclusivity
+            INVALID_COMPANY => None,
+            company => Some(company)
+        };
+        let exclusive_counter = chunk.fetch::<u8>()?; // exclusive_counter
+
+        let is_city = chunk.fetch::<u8>()? != 0; // larger_town
+        chunk.advance::<u8>()?; // layout
+
+        let psa_count = chunk.fetch::<u32>()?; // psa_list
+        for _ in 0..psa_count {
+            chunk.advance::<u32>()?;
+        }
+
+        chunk.advance::<u32>()?; // cargo_produced
+        for _ in 0..30 {
+            chunk.advance::<u8>()?; // null
+        }
+
+        // Cargo generated by the town
+        let mut supplied = [TransportedCargo::default(); NUM_CARGO];
+        for stat in supplied.iter_mut() {
+            stat.old_max = chunk.fetch::<u32>()?; // old_max
+            stat.new_max = chunk.fetch::<u32>()?; // new_max
+            stat.old_act = chunk.fetch::<u32>()?; // old_act
+            stat.new_act = chunk.fetch::<u32>()?; // new_act
+        }
+
+        // Cargo delivered to the town
+        let mut received = [ReceivedCargo::default(); NUM_TE];
+        for (stat, goal) in received.iter_mut().zip(goals) {
+            chunk.advance::<u16>()?; // old_max
+            chunk.advance::<u16>()?; // new_max
+            stat.old_act = chunk.fetch::<u16>()?; // old_act
+            stat.new_act = chunk.fetch::<u16>()?; // new_act
+            stat.goal = goal;
+        }
+
+        Ok(Town {
+            index,
+            xy,
+            name,
+            is_city,
+            population: None,
+            growth_rate,
+            grow_counter,
+            time_until_rebuild,
+            fund_buildings_months,
+            road_build_months,
+            ratings,
+            exclusivity,
+            exclusive_counter,
+            statues: (0..MAX_COMPANIES).filter(|c| statues & (1 << c) != 0).collect(),
+            passengers: supplied[CT_PASSENGERS],
+            mail: supplied[CT_MAIL],
+            goods: received[TE_GOODS],
+            water: received[TE_WATER],
+            food: received[TE_FOOD]
+        })
+    }
+
+    /// Rebuild the population of the towns from the houses of
+    /// the map, like the game does when loading the save
+    pub fn rebuild_population(towns: &mut [Town], map: &Map) {
+
+        let houses = House::list_by_town(map);
+
+        for town in towns.iter_mut() {
+            town.population = houses.get(&town.index)
+                .map_or(Some(0), |h| h.iter().map(|house| house.population()).sum());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::map::Map;
+    use crate::town::{Town, TownRating, AuthorityRating, TransportedCargo, ReceivedCargo};
+
+    #[test]
+    fn base() -> Result<(), String> {
+
+        let towns = Town::parse_list(&CITY)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(2, towns.len());
+
+        assert_eq!(Town {
+            index: 0,
+            xy: 229514,
+            name: "Drenninghall".to_string(),
+            is_city: false,
+            population: None,
+            growth_rate: 160,
+            grow_counter: 70,
+            time_until_rebuild: 167,
+            fund_buildings_months: 0,
+            road_build_months: 0,
+            ratings: vec![],
+            exclusivity: None,
+            exclusive_counter: 0,
+            statues: vec![],
+            passengers: TransportedCargo { old_max: 165, new_max: 54, old_act: 0, new_act: 0 },
+            mail: TransportedCargo { old_max: 17, new_max: 1, old_act: 0, new_act: 0 },
+            goods: ReceivedCargo::default(),
+            water: ReceivedCargo::default(),
+            food: ReceivedCargo::default()
+        }, towns[0]);
+
+        assert_eq!(2, towns[1].index);
+        assert_eq!("Test Ville".to_string(), towns[1].name);
+
+        // Name generated by a NewGRF
+        let mut buffer = CITY.to_vec();
+        buffer[11] = 0x4e; // townnamegrfid
+        let towns = Town::parse_list(&buffer)
+            .map_err(|e| e.to_string())?;
+        assert_eq!("#0", towns[0].name);
+
+        Ok(())
+    }
+
+    #[test]
+    fn authority() -> Result<(), String> {
+
+        let towns = Town::parse_list(&CITY)
+            .map_err(|e| e.to_string())?;
+
+        let town = &towns[1];
+
+        assert_eq!(vec![
+            AuthorityRating { company: 0, points: 1000 },
+            AuthorityRating { company: 1, points: -250 },
+        ], town.ratings);
+        assert_eq!(TownRating::Outstanding, town.ratings[0].rating());
+        assert_eq!(TownRating::VeryPoor, town.ratings[1].rating());
+
+        assert_eq!(Some(1), town.exclusivity);
+        assert_eq!(6, town.exclusive_counter);
+        assert_eq!(vec![1], town.statues);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cargo() -> Result<(), String> {
+
+        let towns = Town::parse_list(&CITY)
+            .map_err(|e| e.to_string())?;
+
+        let town = &towns[1];
+
+        assert_eq!(66, town.passengers.old_act);
+        assert_eq!(40, town.passengers.percent_transported());
+        assert_eq!(0, town.mail.percent_transported());
+        assert_eq!(ReceivedCargo { old_act: 30, new_act: 0, goal: 40 }, town.goods);
+
+        assert_eq!(0, TransportedCargo::default().percent_transported());
+
+        Ok(())
+    }
+
+    #[test]
+    fn rating_thresholds() {
+        assert_eq!(TownRating::Appalling, TownRating::from_points(-1000));
+        assert_eq!(TownRating::Appalling, TownRating::from_points(-400));
+        assert_eq!(TownRating::VeryPoor, TownRating::from_points(-399));
+        assert_eq!(TownRating::Poor, TownRating::from_points(0));
+        assert_eq!(TownRating::Mediocre, TownRating::from_points(200));
+        assert_eq!(TownRating::Good, TownRating::from_points(201));
+        assert_eq!(TownRating::VeryGood, TownRating::from_points(500));
+        assert_eq!(TownRating::Excellent, TownRating::from_points(800));
+        assert_eq!(TownRating::Outstanding, TownRating::from_points(801));
+    }
+
+    #[test]
+    fn population() -> Result<(), String> {
+
+        let mut towns = Town::parse_list(&CITY)
+            .map_err(|e| e.to_string())?;
+
+        // 4x1 map with town houses, one still built, and a stadium
+        let map = Map {
+            width: 4,
+            height: 1,
+            tile_type: vec![0x30, 0x30, 0x30, 0x30],
+            tile_height: vec![0; 4],
+            m1: vec![0; 4],
+            m2: vec![0, 0, 0, 2],
+            m3: vec![0x80, 0x80, 0x00, 0x80],
+            m4: vec![6, 6, 6, 20],
+            m5: vec![0; 4],
+            m6: vec![0; 4],
+            m7: vec![0; 4],
+            m8: vec![0; 4],
+        };
+
+        Town::rebuild_population(&mut towns, &map);
+
+        assert_eq!(Some(60), towns[0].population);
+        assert_eq!(Some(65), towns[1].population);
+
+        // A house missing on the table, like the ones of the other climates
+        let mut map = map;
+        map.m4[0] = 44;
+        Town::rebuild_population(&mut towns, &map);
+
+        assert_eq!(None, towns[0].population);
+        assert_eq!(Some(65), towns[1].population);
+
+        Ok(())
+    }
+
+    #[test]
+    fn truncated() {
+
+        let mut buffer = CITY.to_vec();
+        buffer.truncate(60);
+
+        assert!(Town::parse_list(&buffer).is_err());
+    }
+
+    /// Two towns, with a free index between them
+    static CITY: [u8; 1509] = [
+        0x43, 0x49, 0x54, 0x59, 0x01, 0x82, 0xe9, 0x00, 0x03, 0x80, 0x8a, 0x00, 0x00, 0x00, 0x00, 0x20,
+        0xc0, 0xec, 0xf3, 0xea, 0xdb, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x01, 0xf4, 0x01, 0xf4, 0x01,
+        0xf4, 0x01, 0xf4, 0x01, 0xf4, 0x01, 0xf4, 0x01, 0xf4, 0x01, 0xf4, 0x01, 0xf4, 0x01, 0xf4, 0x01,
+        0xf4, 0x01, 0xf4, 0x01, 0xf4, 0x01, 0xf4, 0x01, 0xf4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xa7, 0x00, 0x46, 0x00, 0xa0, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa5, 0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x03, 0x60, 0x80, 0x00, 0x0c, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x25, 0x00,
+        0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x01,
+        0x82, 0xf3, 0x00, 0x03, 0x80, 0x8a, 0x00, 0x00, 0x00, 0x00, 0x20, 0xc0, 0xec, 0xf3, 0xea, 0xdb,
+        0x0a, 0x54, 0x65, 0x73, 0x74, 0x20, 0x56, 0x69, 0x6c, 0x6c, 0x65, 0x06, 0x00, 0x02, 0x00, 0x03,
+        0x03, 0xe8, 0xff, 0x06, 0x01, 0xf4, 0x01, 0xf4, 0x01, 0xf4, 0x01, 0xf4, 0x01, 0xf4, 0x01, 0xf4,
+        0x01, 0xf4, 0x01, 0xf4, 0x01, 0xf4, 0x01, 0xf4, 0x01, 0xf4, 0x01, 0xf4, 0x01, 0xf4, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa7, 0x00, 0x46, 0x00, 0xa0, 0x00, 0x00, 0x01, 0x06,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa5, 0x00, 0x00, 0x00, 0x36,
+        0x00, 0x00, 0x00, 0x42, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x60, 0x80, 0x00, 0x0c, 0x00, 0x0c,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x25,
+        0x00, 0x00, 0x00, 0x25, 0x00,
+    ];
+}
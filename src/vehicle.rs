@@ -1,36 +1,133 @@
 //! Vehicles related types
+//!
+//! All the vehicles share the same pool, the parts of a train
+//! are vehicles linked by the next references.
+//!
+//! Original source:
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/saveload/vehicle_sl.cpp
+//! - https://github.com/OpenTTD/OpenTTD/blob/release/1.8/src/train.h
 
+use std::ops::Range;
+
+use crate::bitmath::{has_bit, to_percent16};
+use crate::cargo_packet::CargoPacket;
 use crate::chunk_reader::ChunkReader;
+use crate::date::Date;
 use crate::error::Error;
+use crate::money::{Money, currencies};
+use crate::tile::Owner;
+
+/// Types of the vehicles, saved first on each vehicle
+const VEH_TRAIN: u8 = 0;
+const VEH_ROAD: u8 = 1;
+const VEH_SHIP: u8 = 2;
+const VEH_AIRCRAFT: u8 = 3;
+
+/// No station, like the vehicles never stopped at one
+const INVALID_STATION: u16 = 0xFFFF;
+
+/// No speed limit on the current order
+const UNLIMITED_SPEED: u16 = 0xFFFF;
+
+/// Flags of the vehicles, saved as bits
+const VF_TIMETABLE_STARTED: u8 = 3;
+const VF_AUTOFILL_TIMETABLE: u8 = 4;
+
+/// Status of the vehicles, saved as flags
+const VS_STOPPED: u8 = 0x02;
+const VS_CRASHED: u8 = 0x80;
+
+/// Parts of a train, saved as bits of the subtype
+const GVSF_FRONT: u8 = 0;
+const GVSF_ARTICULATED_PART: u8 = 1;
+const GVSF_WAGON: u8 = 2;
+const GVSF_ENGINE: u8 = 3;
+const GVSF_FREE_WAGON: u8 = 4;
+const GVSF_MULTIHEADED: u8 = 5;
+
+/// Train flags
+const VRF_REVERSING: u8 = 0;
+const VRF_TRAIN_STUCK: u8 = 8;
+const VRF_LEAVING_STATION: u8 = 9;
+
+/// Built-in road vehicle engines, all of them are not trams
+const ROAD_ENGINES: Range<u16> = 116..204;
+
+/// States of the road vehicles, the others are the trackdirs
+const RVSB_IN_DEPOT: u8 = 0xFE;
+const RVSB_WORMHOLE: u8 = 0xFF;
+const RVS_IN_ROAD_STOP: u8 = 5;
+const RVS_IN_DT_ROAD_STOP: u8 = 6;
+const RVSB_DRIVE_SIDE: u8 = 0x10;
+const RVSB_TRACKDIR_MASK: u8 = 0x0F;
+
+/// States of the ships, the others are the track bits
+const TRACK_BIT_WORMHOLE: u8 = 0x40;
+const TRACK_BIT_DEPOT: u8 = 0x80;
+
+/// Parts of an aircraft, saved as the subtype
+const AIR_HELICOPTER: u8 = 0;
+const AIR_AIRCRAFT: u8 = 2;
+
+/// Types of the vehicles, without the effects and the disasters
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum VehicleType {
+    Train,
+    RoadVehicle,
+    Ship,
+    Aircraft,
+}
+
+impl TryFrom<u8> for VehicleType {
+    type Error = Error;
+
+    fn try_from(vehicle_type: u8) -> Result<Self, Self::Error> {
+        match vehicle_type {
+            VEH_TRAIN => Ok(VehicleType::Train),
+            VEH_ROAD => Ok(VehicleType::RoadVehicle),
+            VEH_SHIP => Ok(VehicleType::Ship),
+            VEH_AIRCRAFT => Ok(VehicleType::Aircraft),
+            t => Err(Error::DataCorruption(format!("Unknown vehicle type: {}", t)))
+        }
+    }
+}
 
 /// Vehicles informations
 #[derive(Debug, PartialEq)]
 pub struct Vehicles {
+    /// Trains parts, the engines and the wagons
     pub trains: Vec<Train>,
+    /// Road vehicles parts, with the articulated ones
+    pub road_vehicles: Vec<RoadVehicle>,
+    /// Ships
+    pub ships: Vec<Ship>,
+    /// Aircraft parts, with the shadows and the rotors
+    pub aircraft: Vec<Aircraft>,
 }
 
 impl Vehicles {
 
     /// Parse the vehicles information
-    pub fn parse(buffer: &Vec<u8>) -> Result<Vehicles, Error> {
+    ///
+    /// The effect vehicles and the disasters are skipped, the
+    /// amount of cargo is counted on the cargo packets.
+    pub fn parse(buffer: &[u8]) -> Result<Vehicles, Error> {
 
         let mut chunk = ChunkReader::find(buffer, "VEHS")?; // 56 45 48 53
 
-        // Fields from https://github.com/OpenTTD/OpenTTD/blob/8537fa72063a7376065fd996fa249cc7dbfdb2f3/src/saveload/vehicle_sl.cpp#L590
-
         let mut trains = vec![];
+        let mut road_vehicles = vec![];
+        let mut ships = vec![];
+        let mut aircraft = vec![];
 
         loop {
-            let tp = chunk.fetch::<u8>()?; // vehicle type
-
-            println!("tp {}", tp);
-
-            match tp {
-                0 => { // Train
-                    trains.push(Train::parse(&mut chunk)?);
-                },
+            match chunk.fetch::<u8>()? { // type
+                VEH_TRAIN => trains.push(Train::parse(&mut chunk)?),
+                VEH_ROAD => road_vehicles.push(RoadVehicle::parse(&mut chunk)?),
+                VEH_SHIP => ships.push(Ship::parse(&mut chunk)?),
+                VEH_AIRCRAFT => aircraft.push(Aircraft::parse(&mut chunk)?),
                 _ => {}
-            };
+            }
 
             match chunk.advance_slice()? {
                 Some(c) => chunk = c,
@@ -38,17 +135,287 @@ impl Vehicles {
             }
         }
 
-        println!("trains {}", trains.len());
+        let packets = CargoPacket::parse_list(buffer)?;
+        let vehicles = trains.iter_mut().map(|t| &mut t.vehicle)
+            .chain(road_vehicles.iter_mut().map(|r| &mut r.vehicle))
+            .chain(ships.iter_mut().map(|s| &mut s.vehicle))
+            .chain(aircraft.iter_mut().map(|a| &mut a.vehicle));
+        for vehicle in vehicles {
+            vehicle.cargo_count = CargoPacket::find(&packets, &vehicle.packets).iter()
+                .map(|p| p.count as u32)
+                .sum();
+        }
 
         Ok(Vehicles {
-            trains
+            trains,
+            road_vehicles,
+            ships,
+            aircraft
         })
     }
+
+    /// All the vehicles, with their type
+    pub fn iter(&self) -> impl Iterator<Item = (VehicleType, &Vehicle)> {
+        self.trains.iter().map(|t| (VehicleType::Train, &t.vehicle))
+            .chain(self.road_vehicles.iter().map(|r| (VehicleType::RoadVehicle, &r.vehicle)))
+            .chain(self.ships.iter().map(|s| (VehicleType::Ship, &s.vehicle)))
+            .chain(self.aircraft.iter().map(|a| (VehicleType::Aircraft, &a.vehicle)))
+    }
 }
 
-/// Train informations
+/// Fields shared by all the vehicle types
+#[derive(Debug, PartialEq)]
+pub struct Vehicle {
+    /// Index of the vehicle on the pool
+    pub index: usize,
+    /// Subtype, the part of a train or the kind of the other vehicles
+    pub subtype: u8,
+    /// Next part of the vehicle, like the wagons of a train
+    pub next: Option<usize>,
+    /// Custom name, empty with the default one
+    pub name: String,
+    /// Number of the vehicle shown by the game, zero on the wagons
+    pub unit_number: u16,
+    /// Owner of the vehicle
+    pub owner: Owner,
+    /// Tile of the vehicle position
+    pub tile: u32,
+    /// Tile of the current destination
+    pub dest_tile: u32,
+    /// Engine id of the vehicle
+    pub engine_type: u16,
+    /// Current speed, in the internal units of the vehicle type
+    pub speed: u16,
+    /// Speed limit set on the current order, none without limit
+    ///
+    /// This is not the max speed of the vehicle, the one of the engine
+    /// isn't saved.
+    pub order_speed_limit: Option<u16>,
+    /// Status flags, like stopped or crashed
+    pub status: u8,
+    /// Last station where the vehicle stopped
+    pub last_station_visited: Option<usize>,
+    /// Order list, only on the first part of the vehicle
+    pub order_list: Option<usize>,
+    /// Position of the current order on the list, with the implicit orders
+    ///
+    /// This is the last real order, never an implicit one.
+    pub current_order: u8,
+    /// Ticks since the start of the current order
+    pub current_order_time: u32,
+    /// Date of the first order of the timetable, when set by the player
+    pub timetable_start: Option<Date>,
+    /// Ticks of delay on the timetable, negative when early
+    pub lateness_counter: i32,
+    /// Vehicle flags, like the timetable started
+    pub flags: u16,
+    /// Cargo id, on the cargos of the climate
+    pub cargo_type: u8,
+    /// Capacity of the vehicle for the cargo
+    pub cargo_capacity: u16,
+    /// Amount of cargo loaded, zero until the packets are counted
+    pub cargo_count: u32,
+    /// Indexes of the cargo packets loaded on the vehicle
+    pub packets: Vec<usize>,
+    /// Age in days
+    pub age: i32,
+    /// Max age in days, before the vehicle is getting old
+    pub max_age: i32,
+    /// Reliability, from 0 to 65535
+    pub reliability: u16,
+    /// Date of the last service in a depot
+    pub date_of_last_service: Date,
+    /// Year of the construction
+    pub build_year: i32,
+    /// Profit of the current year
+    pub profit_this_year: Money,
+    /// Profit of the previous year
+    pub profit_last_year: Money,
+    /// Current value of the vehicle
+    pub value: Money,
+}
+
+impl Vehicle {
+
+    /// Parse the fields shared by all the vehicle types, after the type
+    pub fn parse(chunk: &mut ChunkReader) -> Result<Vehicle, Error> {
+
+        let index = chunk.slice_idx;
+        let subtype = chunk.fetch::<u8>()?; // subtype
+        let next = parse_reference(chunk.fetch::<u32>()?); // next
+        let name = chunk.fetch::<String>()?; // name
+        let unit_number = chunk.fetch::<u16>()?; // unitnumber
+        let owner = Owner::from(chunk.fetch::<u8>()?); // owner
+        let tile = chunk.fetch::<u32>()?; // tile
+        let dest_tile = chunk.fetch::<u32>()?; // dest_tile
+        chunk.advance::<u32>()?; // x_pos
+        chunk.advance::<u32>()?; // y_pos
+        chunk.advance::<i32>()?; // z_pos
+        chunk.advance::<u8>()?; // direction
+        chunk.advance::<u8>()?; // spritenum
+        let engine_type = chunk.fetch::<u16>()?; // engine_type
+        let speed = chunk.fetch::<u16>()?; // cur_speed
+        chunk.advance::<u8>()?; // subspeed
+        chunk.advance::<u8>()?; // acceleration
+        chunk.advance::<u8>()?; // progress
+        let status = chunk.fetch::<u8>()?; // vehstatus
+        let last_station_visited = match chunk.fetch::<u16>()? { // last_station_visited
+            INVALID_STATION => None,
+            station => Some(station as usize)
+        };
+        chunk.advance::<u16>()?; // last_loading_station
+        let cargo_type = chunk.fetch::<u8>()?; // cargo_type
+        chunk.advance::<u8>()?; // cargo_subtype
+        let cargo_capacity = chunk.fetch::<u16>()?; // cargo_cap
+        chunk.advance::<u16>()?; // refit_cap
+        let num_packets = chunk.fetch::<u32>()?; // cargo.packets
+        let packets = chunk.fetch_array::<u32>(num_packets as usize)?
+            .into_iter()
+            .filter_map(parse_reference)
+            .collect();
+        chunk.fetch_array::<u32>(4)?; // cargo.action_counts
+        chunk.advance::<u16>()?; // cargo_age_counter
+        chunk.advance::<u8>()?; // day_counter
+        chunk.advance::<u8>()?; // tick_counter
+        chunk.advance::<u8>()?; // running_ticks
+        chunk.advance::<u8>()?; // cur_implicit_order_index
+        let current_order = chunk.fetch::<u8>()?; // cur_real_order_index
+        chunk.advance::<u8>()?; // current_order.type
+        chunk.advance::<u8>()?; // current_order.flags
+        chunk.advance::<u16>()?; // current_order.dest
+        chunk.advance::<u8>()?; // current_order.refit_cargo
+        chunk.advance::<u16>()?; // current_order.wait_time
+        chunk.advance::<u16>()?; // current_order.travel_time
+        let order_speed_limit = match chunk.fetch::<u16>()? { // current_order.max_speed
+            UNLIMITED_SPEED => None,
+            speed => Some(speed)
+        };
+        let timetable_start = Some(Date(chunk.fetch::<i32>()?)) // timetable_start
+            .filter(|d| d.0 != 0);
+        let order_list = parse_reference(chunk.fetch::<u32>()?); // orders
+        let age = chunk.fetch::<i32>()?; // age
+        let max_age = chunk.fetch::<i32>()?; // max_age
+        let date_of_last_service = Date(chunk.fetch::<i32>()?); // date_of_last_service
+        chunk.advance::<u16>()?; // service_interval
+        let reliability = chunk.fetch::<u16>()?; // reliability
+        chunk.advance::<u16>()?; // reliability_spd_dec
+        chunk.advance::<u8>()?; // breakdown_ctr
+        chunk.advance::<u8>()?; // breakdown_delay
+        chunk.advance::<u8>()?; // breakdowns_since_last_service
+        chunk.advance::<u8>()?; // breakdown_chance
+        let build_year = chunk.fetch::<i32>()?; // build_year
+        chunk.advance::<u16>()?; // load_unload_ticks
+        chunk.advance::<u16>()?; // _cargo_paid_for
+        let flags = chunk.fetch::<u16>()?; // vehicle_flags
+        let profit_this_year = Money::new(chunk.fetch::<i64>()?, currencies::GBP); // profit_this_year
+        let profit_last_year = Money::new(chunk.fetch::<i64>()?, currencies::GBP); // profit_last_year
+        let value = Money::new(chunk.fetch::<i64>()?, currencies::GBP); // value
+        chunk.advance::<u8>()?; // random_bits
+        chunk.advance::<u8>()?; // waiting_triggers
+        chunk.advance::<u32>()?; // next_shared
+        chunk.advance::<u16>()?; // group_id
+        let current_order_time = chunk.fetch::<u32>()?; // current_order_time
+        let lateness_counter = chunk.fetch::<i32>()?; // lateness_counter
+
+        Ok(Vehicle {
+            index,
+            subtype,
+            next,
+            name,
+            unit_number,
+            owner,
+            tile,
+            dest_tile,
+            engine_type,
+            speed,
+            order_speed_limit,
+            status,
+            last_station_visited,
+            order_list,
+            current_order,
+            current_order_time,
+            timetable_start,
+            lateness_counter,
+            flags,
+            cargo_type,
+            cargo_capacity,
+            cargo_count: 0,
+            packets,
+            age,
+            max_age,
+            reliability,
+            date_of_last_service,
+            build_year,
+            profit_this_year,
+            profit_last_year,
+            value
+        })
+    }
+
+    /// Reliability shown by the game, from 0 to 100
+    pub fn reliability_percent(&self) -> u8 {
+        to_percent16(self.reliability)
+    }
+
+    /// Check if the vehicle is stopped by his owner
+    pub fn is_stopped(&self) -> bool {
+        self.status & VS_STOPPED != 0
+    }
+
+    /// Check if the vehicle is crashed
+    pub fn is_crashed(&self) -> bool {
+        self.status & VS_CRASHED != 0
+    }
+
+    /// Check if the vehicle is running on his timetable
+    pub fn is_timetable_started(&self) -> bool {
+        has_bit(self.flags as u32, VF_TIMETABLE_STARTED)
+    }
+
+    /// Check if the timetable is filled with the measured times
+    pub fn is_timetable_autofilled(&self) -> bool {
+        has_bit(self.flags as u32, VF_AUTOFILL_TIMETABLE)
+    }
+}
+
+/// Order of a stuck train, to pass a signal
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ForceProceed {
+    None,
+    /// Leaving the station, or a depot
+    Stuck,
+    /// Ignoring the signal
+    Signal,
+}
+
+impl TryFrom<u8> for ForceProceed {
+    type Error = Error;
+
+    fn try_from(force_proceed: u8) -> Result<Self, Self::Error> {
+        match force_proceed {
+            0 => Ok(ForceProceed::None),
+            1 => Ok(ForceProceed::Stuck),
+            2 => Ok(ForceProceed::Signal),
+            f => Err(Error::DataCorruption(format!("Unknown train force proceed: {}", f)))
+        }
+    }
+}
+
+/// Train informations, a part of a train
 #[derive(Debug, PartialEq)]
 pub struct Train {
+    /// Fields of the vehicle
+    pub vehicle: Vehicle,
+    /// Step of the animation of a crashed train
+    pub crash_anim_pos: u16,
+    /// Order to pass the red signals
+    pub force_proceed: ForceProceed,
+    /// Rail type, 0 is the normal rail on the default rail types
+    pub railtype: u8,
+    /// Track of the tile, saved as the TrackBits flags
+    pub track: u8,
+    /// Train flags, like reversing or stuck
+    pub flags: u16,
 }
 
 impl Train {
@@ -56,38 +423,523 @@ impl Train {
     /// Parse the train information
     pub fn parse(chunk: &mut ChunkReader) -> Result<Train, Error> {
 
-        let sub = chunk.fetch::<u8>()?; // subtype
-        let next = chunk.fetch::<u8>()?; // next vehicle
+        let vehicle = Vehicle::parse(chunk)?;
+
+        let crash_anim_pos = chunk.fetch::<u16>()?; // crash_anim_pos
+        let force_proceed = ForceProceed::try_from(chunk.fetch::<u8>()?)?; // force_proceed
+        let railtype = chunk.fetch::<u8>()?; // railtype
+        let track = chunk.fetch::<u8>()?; // track
+        let flags = chunk.fetch::<u16>()?; // flags
+        chunk.advance::<u16>()?; // wait_counter
+        chunk.advance::<u16>()?; // gv_flags
+
+        Ok(Train {
+            vehicle,
+            crash_anim_pos,
+            force_proceed,
+            railtype,
+            track,
+            flags
+        })
+    }
+
+    /// Check if this is the first part of a train
+    pub fn is_front(&self) -> bool {
+        has_bit(self.vehicle.subtype as u32, GVSF_FRONT)
+    }
+
+    /// Check if this is an articulated part of an engine or a wagon
+    pub fn is_articulated_part(&self) -> bool {
+        has_bit(self.vehicle.subtype as u32, GVSF_ARTICULATED_PART)
+    }
+
+    /// Check if this is a wagon
+    pub fn is_wagon(&self) -> bool {
+        has_bit(self.vehicle.subtype as u32, GVSF_WAGON)
+    }
 
-        println!("{} {}", sub, next);
+    /// Check if this is an engine
+    pub fn is_engine(&self) -> bool {
+        has_bit(self.vehicle.subtype as u32, GVSF_ENGINE)
+    }
 
-        todo!("??");
+    /// Check if this is the first wagon of wagons without engine, in a depot
+    pub fn is_free_wagon(&self) -> bool {
+        has_bit(self.vehicle.subtype as u32, GVSF_FREE_WAGON)
+    }
 
-        Ok(Self {
+    /// Check if this is the rear engine of a multiheaded engine
+    pub fn is_multiheaded(&self) -> bool {
+        has_bit(self.vehicle.subtype as u32, GVSF_MULTIHEADED)
+    }
+
+    /// Check if the train is reversing
+    pub fn is_reversing(&self) -> bool {
+        has_bit(self.flags as u32, VRF_REVERSING)
+    }
+
+    /// Check if the train is stuck, waiting for a free path
+    pub fn is_stuck(&self) -> bool {
+        has_bit(self.flags as u32, VRF_TRAIN_STUCK)
+    }
+
+    /// Check if the train is leaving a station
+    pub fn is_leaving_station(&self) -> bool {
+        has_bit(self.flags as u32, VRF_LEAVING_STATION)
+    }
+}
+
+/// Kind of road of a road vehicle
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RoadType {
+    Road,
+    Tram,
+}
+
+/// Position of a road vehicle
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RoadVehicleState {
+    /// Driving on a road, with the trackdir
+    Driving(u8),
+    /// Inside a bay of a road stop
+    InRoadStop,
+    /// Inside a drive through road stop
+    InDriveThroughRoadStop,
+    /// Inside a depot
+    InDepot,
+    /// Inside a tunnel, or on a bridge
+    Wormhole,
+}
+
+impl From<u8> for RoadVehicleState {
+    fn from(state: u8) -> Self {
+        match state {
+            RVSB_IN_DEPOT => RoadVehicleState::InDepot,
+            RVSB_WORMHOLE => RoadVehicleState::Wormhole,
+            s if has_bit(s as u32, RVS_IN_DT_ROAD_STOP) => RoadVehicleState::InDriveThroughRoadStop,
+            s if has_bit(s as u32, RVS_IN_ROAD_STOP) => RoadVehicleState::InRoadStop,
+            s => RoadVehicleState::Driving(s & RVSB_TRACKDIR_MASK)
+        }
+    }
+}
+
+/// Road vehicle informations, a part of a road vehicle
+#[derive(Debug, PartialEq)]
+pub struct RoadVehicle {
+    /// Fields of the vehicle
+    pub vehicle: Vehicle,
+    /// Position on the road
+    pub state: RoadVehicleState,
+    /// Step of the vehicle on the current tile
+    pub frame: u8,
+    /// Ticks blocked by another vehicle
+    pub blocked_ctr: u16,
+    /// Overtaking state, the drive side flag while overtaking
+    pub overtaking: u8,
+    /// Ticks since the start of the overtaking
+    pub overtaking_ctr: u8,
+    /// Step of the animation of a crashed vehicle
+    pub crashed_ctr: u16,
+    /// Ticks since the start of the reversing
+    pub reverse_ctr: u8,
+}
+
+impl RoadVehicle {
+
+    /// Parse the road vehicle information
+    pub fn parse(chunk: &mut ChunkReader) -> Result<RoadVehicle, Error> {
+
+        let vehicle = Vehicle::parse(chunk)?;
+
+        let state = RoadVehicleState::from(chunk.fetch::<u8>()?); // state
+        let frame = chunk.fetch::<u8>()?; // frame
+        let blocked_ctr = chunk.fetch::<u16>()?; // blocked_ctr
+        let overtaking = chunk.fetch::<u8>()?; // overtaking
+        let overtaking_ctr = chunk.fetch::<u8>()?; // overtaking_ctr
+        let crashed_ctr = chunk.fetch::<u16>()?; // crashed_ctr
+        let reverse_ctr = chunk.fetch::<u8>()?; // reverse_ctr
+        chunk.advance::<u16>()?; // gv_flags
+
+        Ok(RoadVehicle {
+            vehicle,
+            state,
+            frame,
+            blocked_ctr,
+            overtaking,
+            overtaking_ctr,
+            crashed_ctr,
+            reverse_ctr
+        })
+    }
+
+    /// Kind of road, from the engine
+    ///
+    /// The tram flag is not saved, only the built-in engines
+    /// are known to be road vehicles.
+    pub fn road_type(&self) -> Option<RoadType> {
+        ROAD_ENGINES.contains(&self.vehicle.engine_type).then_some(RoadType::Road)
+    }
+
+    /// Check if this is the first part of a road vehicle
+    pub fn is_front(&self) -> bool {
+        has_bit(self.vehicle.subtype as u32, GVSF_FRONT)
+    }
+
+    /// Check if this is an articulated part of a road vehicle
+    pub fn is_articulated_part(&self) -> bool {
+        has_bit(self.vehicle.subtype as u32, GVSF_ARTICULATED_PART)
+    }
+
+    /// Check if the vehicle is overtaking another one
+    pub fn is_overtaking(&self) -> bool {
+        self.overtaking & RVSB_DRIVE_SIDE != 0
+    }
+}
+
+/// Position of a ship
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ShipState {
+    /// Sailing, with the track bits of the tile
+    Sailing(u8),
+    /// Inside a depot
+    InDepot,
+    /// On an aqueduct
+    Wormhole,
+}
+
+impl From<u8> for ShipState {
+    fn from(state: u8) -> Self {
+        match state {
+            TRACK_BIT_DEPOT => ShipState::InDepot,
+            TRACK_BIT_WORMHOLE => ShipState::Wormhole,
+            s => ShipState::Sailing(s)
+        }
+    }
+}
+
+/// Ship informations
+#[derive(Debug, PartialEq)]
+pub struct Ship {
+    /// Fields of the vehicle
+    pub vehicle: Vehicle,
+    /// Position on the water
+    pub state: ShipState,
+}
+
+impl Ship {
+
+    /// Parse the ship information
+    pub fn parse(chunk: &mut ChunkReader) -> Result<Ship, Error> {
+
+        let vehicle = Vehicle::parse(chunk)?;
+
+        let state = ShipState::from(chunk.fetch::<u8>()?); // state
+
+        Ok(Ship {
+            vehicle,
+            state
+        })
+    }
+}
+
+/// Movement state of an aircraft, on the airport or in the air
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AircraftState {
+    /// Moving on the airport, to any position
+    ToAll,
+    Hangar,
+    /// At a terminal, numbered from 1
+    Terminal(u8),
+    /// At a helipad, numbered from 1
+    Helipad(u8),
+    Takeoff,
+    StartTakeoff,
+    EndTakeoff,
+    HeliTakeoff,
+    Flying,
+    Landing,
+    EndLanding,
+    HeliLanding,
+    HeliEndLanding,
+}
+
+impl TryFrom<u8> for AircraftState {
+    type Error = Error;
+
+    fn try_from(state: u8) -> Result<Self, Self::Error> {
+        match state {
+            0 => Ok(AircraftState::ToAll),
+            1 => Ok(AircraftState::Hangar),
+            2..=7 => Ok(AircraftState::Terminal(state - 1)),
+            8 | 9 => Ok(AircraftState::Helipad(state - 7)),
+            10 => Ok(AircraftState::Takeoff),
+            11 => Ok(AircraftState::StartTakeoff),
+            12 => Ok(AircraftState::EndTakeoff),
+            13 => Ok(AircraftState::HeliTakeoff),
+            14 => Ok(AircraftState::Flying),
+            15 => Ok(AircraftState::Landing),
+            16 => Ok(AircraftState::EndLanding),
+            17 => Ok(AircraftState::HeliLanding),
+            18 => Ok(AircraftState::HeliEndLanding),
+            19 | 20 => Ok(AircraftState::Terminal(state - 12)),
+            21 => Ok(AircraftState::Helipad(3)),
+            s => Err(Error::DataCorruption(format!("Unknown aircraft state: {}", s)))
+        }
+    }
+}
+
+/// Aircraft informations, a part of an aircraft
+///
+/// The planes and the helicopters are followed by their shadow,
+/// and the helicopters by their rotor.
+#[derive(Debug, PartialEq)]
+pub struct Aircraft {
+    /// Fields of the vehicle
+    pub vehicle: Vehicle,
+    /// Step of the animation of a crashed aircraft
+    pub crashed_counter: u16,
+    /// Position on the movement graph of the airport
+    pub pos: u8,
+    /// Station of the destination airport
+    pub target_airport: Option<usize>,
+    /// Movement state
+    pub state: AircraftState,
+}
+
+impl Aircraft {
+
+    /// Parse the aircraft information
+    pub fn parse(chunk: &mut ChunkReader) -> Result<Aircraft, Error> {
+
+        let vehicle = Vehicle::parse(chunk)?;
+
+        let crashed_counter = chunk.fetch::<u16>()?; // crashed_counter
+        let pos = chunk.fetch::<u8>()?; // pos
+        let target_airport = match chunk.fetch::<u16>()? { // targetairport
+            INVALID_STATION => None,
+            station => Some(station as usize)
+        };
+        let state = AircraftState::try_from(chunk.fetch::<u8>()?)?; // state
+        chunk.advance::<u8>()?; // previous_pos
+        chunk.advance::<u8>()?; // last_direction
+        chunk.advance::<u8>()?; // number_consecutive_turns
+        chunk.advance::<u8>()?; // turn_counter
+        chunk.advance::<u8>()?; // flags
+
+        Ok(Aircraft {
+            vehicle,
+            crashed_counter,
+            pos,
+            target_airport,
+            state
         })
     }
+
+    /// Check if this is a plane or a helicopter, not a shadow or a rotor
+    pub fn is_normal_aircraft(&self) -> bool {
+        self.vehicle.subtype <= AIR_AIRCRAFT
+    }
+
+    /// Check if this is a helicopter
+    pub fn is_helicopter(&self) -> bool {
+        self.vehicle.subtype == AIR_HELICOPTER
+    }
 }
 
+/// Index of a referenced vehicle or cargo packet, saved as the index plus one
+fn parse_reference(reference: u32) -> Option<usize> {
+    (reference as usize).checked_sub(1)
+}
 
 #[cfg(test)]
-mod test {
+pub(crate) mod test {
 
     use crate::vehicle::*;
 
     #[test]
     fn base() -> Result<(), String> {
 
-        let vehicles = Vehicles::parse(&VEHS.to_vec())
+        let buffer = [&VEHS[..], &CAPA[..]].concat();
+
+        let vehicles = Vehicles::parse(&buffer)
             .map_err(|e| e.to_string())?;
 
-        assert_eq!(1, vehicles.trains.len());
+        assert_eq!(2, vehicles.trains.len());
+
+        let engine = &vehicles.trains[0];
+        assert!(engine.is_front());
+        assert!(engine.is_engine());
+        assert!(!engine.is_wagon());
+        assert_eq!(Some(192), engine.vehicle.next);
+        assert_eq!(8, engine.vehicle.unit_number);
+        assert_eq!(14, engine.vehicle.engine_type);
+        assert_eq!(Owner::Company(0), engine.vehicle.owner);
+        assert_eq!(951321, engine.vehicle.tile);
+        assert_eq!(Some(63), engine.vehicle.last_station_visited);
+        assert_eq!(Some(7), engine.vehicle.order_list);
+        assert_eq!(None, engine.vehicle.order_speed_limit);
+        assert_eq!(4113, engine.vehicle.age);
+        assert_eq!(7320, engine.vehicle.max_age);
+        assert_eq!(84, engine.vehicle.reliability_percent());
+        assert_eq!(1986, engine.vehicle.build_year);
+        assert_eq!(-218045, engine.vehicle.profit_this_year.original);
+        assert_eq!(4067713, engine.vehicle.profit_last_year.original);
+        assert_eq!(5623, engine.vehicle.value.original);
+        assert_eq!(ForceProceed::None, engine.force_proceed);
+        assert_eq!(0, engine.railtype);
+        assert!(!engine.is_stuck());
 
-        let vehicle = &vehicles.trains[0];
+        let wagon = &vehicles.trains[1];
+        assert_eq!(192, wagon.vehicle.index);
+        assert!(wagon.is_wagon());
+        assert!(!wagon.is_front());
+        assert_eq!(0, wagon.vehicle.unit_number);
+        assert_eq!(None, wagon.vehicle.order_list);
+        assert_eq!(4, wagon.vehicle.cargo_type);
+        assert_eq!(25, wagon.vehicle.cargo_capacity);
+        assert_eq!(vec![89, 186, 260, 282, 32], wagon.vehicle.packets);
+        // Only the first packet is on the fixture
+        assert_eq!(2, wagon.vehicle.cargo_count);
 
+        let mut buffer = buffer.clone();
+        buffer[179] = 3; // force_proceed of the engine
+        assert!(Vehicles::parse(&buffer).is_err());
 
         Ok(())
     }
 
-    static VEHS: [u8; 187] = [0x56, 0x45, 0x48, 0x53, 0x02, 0x4d, 0x1c, 0x00, 0x04, 0x00, 0x00, 0x00, 0xbe, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0d, 0xdc, 0x1f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xf8, 0x00, 0x00, 0x37, 0x79, 0x00, 0x00, 0x00, 0x08, 0x03, 0x05, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x44, 0x00, 0x0a, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x8a, 0x2e, 0x57, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xfe, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x2e, 0x00, 0x00, 0x1c, 0x98, 0x00, 0x0b, 0x22, 0x8b, 0x00, 0x96, 0xff, 0x8b, 0x00, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xcc, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x94, 0xd2, 0xc9, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xfe, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    #[test]
+    fn types() -> Result<(), String> {
+
+        let buffer = [&VEHS_TYPES[..], &CAPA[..]].concat();
+
+        let vehicles = Vehicles::parse(&buffer)
+            .map_err(|e| e.to_string())?;
+
+        assert!(vehicles.trains.is_empty());
+
+        assert_eq!(2, vehicles.aircraft.len());
+        let plane = &vehicles.aircraft[0];
+        assert!(plane.is_normal_aircraft());
+        assert!(!plane.is_helicopter());
+        assert_eq!(5, plane.vehicle.unit_number);
+        assert_eq!(Some(190), plane.vehicle.next);
+        assert_eq!(Some(1), plane.target_airport);
+        assert_eq!(AircraftState::Terminal(1), plane.state);
+        assert_eq!(vec![163], plane.vehicle.packets);
+        assert!(!vehicles.aircraft[1].is_normal_aircraft());
+
+        assert_eq!(1, vehicles.ships.len());
+        let ship = &vehicles.ships[0];
+        assert_eq!(1, ship.vehicle.unit_number);
+        assert_eq!(204, ship.vehicle.engine_type);
+        assert_eq!(ShipState::Sailing(2), ship.state);
+
+        assert_eq!(1, vehicles.road_vehicles.len());
+        let bus = &vehicles.road_vehicles[0];
+        assert!(bus.is_front());
+        assert_eq!(Some(RoadType::Road), bus.road_type());
+        assert_eq!(RoadVehicleState::Driving(0), bus.state);
+        assert!(!bus.is_overtaking());
+        assert_eq!(131, bus.vehicle.speed);
+        assert_eq!(35, bus.vehicle.cargo_capacity);
+
+        let mut buffer = buffer.clone();
+        buffer[186] = 30; // state of the plane
+        assert!(Vehicles::parse(&buffer).is_err());
+
+        Ok(())
+    }
+
+    /// The engine of the train 8 of the example save, and his first wagon
+    pub(crate) static VEHS: [u8; 392] = [
+        0x56, 0x45, 0x48, 0x53, 0x02, 0x80, 0xb6, 0x80, 0xb3, 0x00, 0x09, 0x00, 0x00, 0x00, 0xc1, 0x00,
+        0x00, 0x08, 0x00, 0x00, 0x0e, 0x84, 0x19, 0x00, 0x0e, 0x78, 0x18, 0x00, 0x00, 0x01, 0x98, 0x00,
+        0x00, 0x3a, 0x14, 0x00, 0x00, 0x00, 0x08, 0x03, 0x05, 0x00, 0x0e, 0x00, 0x00, 0x9c, 0x14, 0x01,
+        0x08, 0x00, 0x3f, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x11, 0xba, 0x12, 0x00, 0x00, 0xc3, 0x30, 0x00, 0x3f, 0xfe, 0x00, 0x00, 0x06, 0xf0, 0xff,
+        0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x10, 0x11, 0x00, 0x00, 0x1c,
+        0x98, 0x00, 0x0b, 0x22, 0x57, 0x00, 0x96, 0xd5, 0xab, 0x00, 0x50, 0x00, 0x00, 0x00, 0xdd, 0x00,
+        0x00, 0x07, 0xc2, 0x00, 0x07, 0x00, 0x00, 0x00, 0x08, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfc, 0xac,
+        0x43, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3e, 0x11, 0x81, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x15,
+        0xf7, 0x31, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xfe, 0x00, 0x00, 0x14, 0x22, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0xca, 0x80, 0xc0,
+        0x00, 0x04, 0x00, 0x00, 0x00, 0xc9, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0e, 0x80, 0x19, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x98, 0x00, 0x00, 0x3a, 0x0c, 0x00, 0x00, 0x00, 0x08, 0x03, 0x25,
+        0x00, 0x1f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0xff, 0xff, 0xff, 0xff, 0x04, 0x00, 0x00, 0x19,
+        0x00, 0x19, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x5a, 0x00, 0x00, 0x00, 0xbb, 0x00, 0x00,
+        0x01, 0x05, 0x00, 0x00, 0x01, 0x1b, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x00, 0x00, 0x8f, 0x0f, 0x37, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xfe, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0b, 0x22, 0x57,
+        0x00, 0x00, 0xec, 0xc9, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xc2, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xb8, 0x71, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xff, 0xfe, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    /// A plane with his shadow, a ship and a bus of the example save
+    pub(crate) static VEHS_TYPES: [u8; 740] = [
+        0x56, 0x45, 0x48, 0x53, 0x02, 0x80, 0xba, 0x80, 0xae, 0x03, 0x02, 0x00, 0x00, 0x00, 0xbf, 0x00,
+        0x00, 0x05, 0x00, 0x00, 0x0d, 0xb4, 0x1a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xa6, 0x00,
+        0x00, 0x36, 0xd6, 0x00, 0x00, 0x00, 0x09, 0x05, 0x07, 0x00, 0xe4, 0x00, 0x00, 0xe0, 0x28, 0x0a,
+        0x08, 0x00, 0x01, 0x00, 0x2d, 0x02, 0x00, 0x01, 0x5e, 0x01, 0x5e, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0xa4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x75, 0x3b, 0xe4, 0x17, 0x01, 0x01, 0xc3, 0x31, 0x00, 0x01, 0xfe, 0x00,
+        0x00, 0x06, 0x5c, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00,
+        0x3b, 0x00, 0x00, 0x23, 0xbe, 0x00, 0x0b, 0x22, 0xae, 0x00, 0x64, 0xf0, 0x37, 0x00, 0x50, 0x00,
+        0x00, 0x00, 0x1f, 0x00, 0x00, 0x07, 0xce, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x94, 0x9d, 0x3c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x02, 0x59, 0xf5, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xfe, 0x00, 0x00, 0x01,
+        0xcc, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x01, 0x02, 0x04, 0x02, 0x00, 0x00, 0x00,
+        0x80, 0xb6, 0x80, 0xbe, 0x03, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xa6, 0x00, 0x00, 0x36, 0xd6, 0x00, 0x00,
+        0x00, 0x08, 0x00, 0x00, 0x00, 0xe4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24, 0xff, 0xff, 0xff, 0xff,
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x75, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xfe, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xce, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xff, 0xfe, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0xac, 0x80, 0xbf, 0x02, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x0d, 0x04, 0x0c, 0x00, 0x0d, 0x04, 0x0c, 0x00, 0x00, 0x00,
+        0xc8, 0x00, 0x00, 0x34, 0x1e, 0x00, 0x00, 0x00, 0x00, 0x07, 0x01, 0x00, 0xcc, 0x00, 0x00, 0x00,
+        0x00, 0xf4, 0x08, 0x00, 0x28, 0xff, 0xff, 0x03, 0x00, 0x00, 0xdc, 0x00, 0xdc, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x75, 0x71, 0x80, 0x06, 0x00, 0x00, 0xc3, 0x30, 0x00, 0x28, 0xfe, 0x00, 0x00, 0x05,
+        0xc8, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x24, 0x71, 0x00,
+        0x00, 0x2a, 0xe4, 0x00, 0x0b, 0x22, 0x1e, 0x01, 0x68, 0xe4, 0x56, 0x00, 0x14, 0x00, 0x00, 0x00,
+        0x38, 0x00, 0x00, 0x07, 0xb4, 0x00, 0x02, 0x00, 0x00, 0x00, 0x08, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xfb, 0xd1, 0xf6, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf5, 0x6d, 0x9a, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x02, 0x77, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xfe, 0x00, 0x00, 0x2e, 0x17, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x80, 0xbe, 0x81, 0x16, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x0d, 0x54, 0x24, 0x00, 0x0d, 0x38, 0x1d, 0x00, 0x00, 0x02, 0x44, 0x00, 0x00,
+        0x35, 0x59, 0x00, 0x00, 0x00, 0x08, 0x01, 0x11, 0x00, 0x75, 0x00, 0x83, 0xb9, 0x00, 0xb5, 0x08,
+        0x00, 0x0a, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x23, 0x00, 0x23, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x09, 0x00, 0x00, 0x00, 0x71, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x23, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5c, 0x5f, 0xaf, 0x43, 0x00, 0x00, 0x21, 0x32, 0x00,
+        0x00, 0xfe, 0x01, 0x28, 0x02, 0x9a, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x08, 0x5f, 0x00, 0x00, 0x15, 0x72, 0x00, 0x0b, 0x22, 0x47, 0x00, 0x96, 0xca, 0x91,
+        0x00, 0x50, 0x00, 0x00, 0x00, 0xae, 0x00, 0x00, 0x07, 0xc8, 0x00, 0x14, 0x00, 0x00, 0x00, 0x09,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x9c, 0xbb, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0b, 0x50, 0xec,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x12, 0x75, 0x26, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xfe,
+        0x00, 0x00, 0x01, 0x63, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
 
+    /// The first cargo packet loaded on the wagon, after 89 free slots
+    pub(crate) static CAPA: [u8; 120] = [
+        0x43, 0x41, 0x50, 0x41, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+        0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+        0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+        0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+        0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+        0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x19, 0x00,
+        0x3f, 0x00, 0x0e, 0x78, 0x18, 0x00, 0x0e, 0x78, 0x18, 0x00, 0x02, 0x1a, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xb8, 0x00,
+    ];
 }